@@ -1,4 +1,5 @@
-use std::fs;
+use std::str::FromStr;
+use yYYYY_day_NN::Puzzle;
 
 fn main() {
     // Run registered benchmarks.
@@ -7,14 +8,14 @@ fn main() {
 
 #[divan::bench]
 fn part1() {
-    let input = divan::black_box(fs::read_to_string("./input.txt")).unwrap();
-    let puzzle = Puzzle::from_str(input.as_str()).unwrap();
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR"))).unwrap();
+    let puzzle = Puzzle::from_str(&input).unwrap();
     puzzle.part_1();
 }
 
 #[divan::bench]
 fn part2() {
-    let input = divan::black_box(fs::read_to_string("./input.txt").unwrap());
-    let puzzle = Puzzle::from_str(input.as_str()).unwrap();
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    let puzzle = Puzzle::from_str(&input).unwrap();
     puzzle.part_2();
 }
\ No newline at end of file
@@ -1,9 +1,10 @@
-use std::fs;
+use yYYYY_day_NN::Puzzle;
 
 fn main() -> eyre::Result<()> {
-    let input = fs::read_to_string("./input.txt")?;
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
 
-    let puzzle = Puzzle::from_str(&input).unwrap();
+    let puzzle = input.parse::<Puzzle>()?;
 
     println!("Part 2: {}", puzzle.part_2());
 
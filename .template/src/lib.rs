@@ -1,10 +1,11 @@
+use parsing::ParseError;
 use std::str::FromStr;
 
 pub struct Puzzle {
 }
 
 impl FromStr for Puzzle {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         todo!()
@@ -0,0 +1,56 @@
+use divan::AllocProfiler;
+use std::sync::LazyLock;
+use y2024_day_01::{calculate_similarity_score, calculate_total_distance, SortedLists};
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+/// Ten million lines of five digit IDs like the real input's, the same on every run.
+static GENERATED: LazyLock<String> = LazyLock::new(|| {
+    let mut seed = 0x9E37_79B9_7F4A_7C15_u64;
+    let mut id = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        10_000 + seed % 90_000
+    };
+
+    (0..10_000_000).map(|_| format!("{}   {}\n", id(), id())).collect()
+});
+
+#[divan::bench]
+fn part1() {
+    calculate_total_distance(
+        &divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap())
+    );
+}
+
+#[divan::bench]
+fn part2() {
+    calculate_similarity_score(
+        &divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap())
+    );
+}
+
+#[divan::bench(sample_count = 5)]
+fn generated_part1() {
+    calculate_total_distance(divan::black_box(&GENERATED));
+}
+
+/// Overflows `i32` on this many lines, so only the time is meaningful.
+#[divan::bench(sample_count = 5)]
+fn generated_part2() {
+    calculate_similarity_score(divan::black_box(&GENERATED));
+}
+
+#[divan::bench(sample_count = 5)]
+fn generated_part1_radix() {
+    divan::black_box(GENERATED.as_str()).parse::<SortedLists>().unwrap().total_distance();
+}
+
+#[divan::bench(sample_count = 5)]
+fn generated_part2_radix() {
+    divan::black_box(GENERATED.as_str()).parse::<SortedLists>().unwrap().similarity_score();
+}
@@ -0,0 +1,11 @@
+
+fn main() -> eyre::Result<()> {
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
+
+    let distance = y2024_day_01::calculate_total_distance(&input);
+
+    println!("Part 1: {}", distance);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+
+fn main() -> eyre::Result<()> {
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
+
+    let similarity_score = y2024_day_01::calculate_similarity_score(&input);
+
+    println!("Part 2: {}", similarity_score);
+
+    Ok(())
+}
@@ -0,0 +1,293 @@
+/// Which steps [`Imbalance::width_where`] counts.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Sign {
+    AtLeastZero,
+    AtMostZero,
+}
+
+/// A step function over IDs that starts at zero, such as how many more left IDs than right IDs
+/// are at or below each point. Its steps are kept in blocks of about `√n`, so adding to a range
+/// of steps, or weighing the ones above or below zero, takes `O(√n log n)`.
+#[derive(Debug, Default)]
+pub struct Imbalance {
+    blocks: Vec<Block>,
+    steps: usize,
+}
+
+#[derive(Debug, Default)]
+struct Block {
+    /// Where each step starts, ascending.
+    starts: Vec<i64>,
+    /// How far each step reaches before the next starts, or 0 for the last step.
+    widths: Vec<i64>,
+    /// Each step's height, less `offset`.
+    heights: Vec<i64>,
+    /// Added to every step in the block at once.
+    offset: i64,
+    /// The steps' heights in order, each with the total width of the steps up to it.
+    by_height: Vec<(i64, i64)>,
+}
+
+impl Block {
+    fn first(&self) -> i64 {
+        self.starts[0]
+    }
+
+    fn last(&self) -> i64 {
+        self.starts[self.starts.len() - 1]
+    }
+
+    fn rebuild(&mut self) {
+        let mut steps = self.heights.iter().copied().zip(self.widths.iter().copied()).collect::<Vec<_>>();
+        steps.sort_unstable();
+
+        let mut total = 0;
+        self.by_height = steps
+            .into_iter()
+            .map(|(height, width)| {
+                total += width;
+                (height, total)
+            })
+            .collect();
+    }
+
+    fn total_width(&self) -> i64 {
+        self.by_height.last().map_or(0, |(_, total)| *total)
+    }
+
+    /// The width of the steps up to the first `count` of `by_height`.
+    fn width_of_lowest(&self, count: usize) -> i64 {
+        count.checked_sub(1).map_or(0, |i| self.by_height[i].1)
+    }
+
+    fn width_where(&self, sign: Sign) -> i64 {
+        match sign {
+            Sign::AtLeastZero => {
+                let below = self.by_height.partition_point(|(height, _)| height + self.offset < 0);
+                self.total_width() - self.width_of_lowest(below)
+            }
+            Sign::AtMostZero => {
+                let at_most = self.by_height.partition_point(|(height, _)| height + self.offset <= 0);
+                self.width_of_lowest(at_most)
+            }
+        }
+    }
+
+    fn split_off(&mut self, at: usize) -> Block {
+        let mut other = Block {
+            starts: self.starts.split_off(at),
+            widths: self.widths.split_off(at),
+            heights: self.heights.split_off(at),
+            offset: self.offset,
+            by_height: Vec::new(),
+        };
+        self.rebuild();
+        other.rebuild();
+
+        other
+    }
+}
+
+impl Sign {
+    fn matches(&self, height: i64) -> bool {
+        match self {
+            Sign::AtLeastZero => height >= 0,
+            Sign::AtMostZero => height <= 0,
+        }
+    }
+}
+
+impl Imbalance {
+    /// Steps each block should hold, growing with the number of steps.
+    fn block_size(&self) -> usize {
+        self.steps.isqrt().max(8)
+    }
+
+    /// The block and index of the last step starting at or before `at`.
+    fn find(&self, at: i64) -> Option<(usize, usize)> {
+        let b = self.blocks.partition_point(|x| x.first() <= at).checked_sub(1)?;
+        let i = self.blocks[b].starts.partition_point(|x| *x <= at) - 1;
+
+        Some((b, i))
+    }
+
+    fn start_after(&self, b: usize, i: usize) -> Option<i64> {
+        match self.blocks[b].starts.get(i + 1) {
+            Some(start) => Some(*start),
+            None => self.blocks.get(b + 1).map(Block::first),
+        }
+    }
+
+    /// The height just after `at`.
+    #[cfg(test)]
+    fn height(&self, at: i64) -> i64 {
+        self.find(at)
+            .map_or(0, |(b, i)| self.blocks[b].heights[i] + self.blocks[b].offset)
+    }
+
+    /// Makes sure a step starts at `at`, as high as the one it splits.
+    pub fn add_step(&mut self, at: i64) {
+        let (b, i, height, next) = match self.find(at) {
+            Some((b, i)) if self.blocks[b].starts[i] == at => return,
+            Some((b, i)) => {
+                let next = self.start_after(b, i);
+                let block = &mut self.blocks[b];
+                block.widths[i] = at - block.starts[i];
+                (b, i + 1, block.heights[i], next)
+            }
+            None => match self.blocks.first() {
+                Some(block) => (0, 0, -block.offset, Some(block.first())),
+                None => {
+                    self.blocks.push(Block::default());
+                    (0, 0, 0, None)
+                }
+            },
+        };
+        self.steps += 1;
+        let size = self.block_size();
+
+        let block = &mut self.blocks[b];
+        block.starts.insert(i, at);
+        block.widths.insert(i, next.map_or(0, |x| x - at));
+        block.heights.insert(i, height);
+        if block.starts.len() > 2 * size {
+            let other = block.split_off(block.starts.len() / 2);
+            self.blocks.insert(b + 1, other);
+        } else {
+            block.rebuild();
+        }
+    }
+
+    /// Removes the step starting at `at`, which should be as high as the one before it.
+    pub fn remove_step(&mut self, at: i64) {
+        let Some((b, i)) = self.find(at).filter(|(b, i)| self.blocks[*b].starts[*i] == at) else {
+            return;
+        };
+        let next = self.start_after(b, i);
+
+        let before = match i {
+            0 => b.checked_sub(1).map(|b| (b, self.blocks[b].starts.len() - 1)),
+            _ => Some((b, i - 1)),
+        };
+        if let Some((b, i)) = before {
+            let block = &mut self.blocks[b];
+            block.widths[i] = next.map_or(0, |x| x - block.starts[i]);
+            block.rebuild();
+        }
+
+        let block = &mut self.blocks[b];
+        block.starts.remove(i);
+        block.widths.remove(i);
+        block.heights.remove(i);
+        match block.starts.is_empty() {
+            true => {
+                self.blocks.remove(b);
+            }
+            false => block.rebuild(),
+        }
+        self.steps -= 1;
+        self.rebalance();
+    }
+
+    /// Regroups the steps into even blocks once removals have left too many small ones.
+    fn rebalance(&mut self) {
+        let size = self.block_size();
+        if self.blocks.len() <= 4 * (self.steps / size + 1) {
+            return;
+        }
+
+        let mut steps = Vec::with_capacity(self.steps);
+        for block in self.blocks.drain(..) {
+            let offset = block.offset;
+            steps.extend(
+                block
+                    .starts
+                    .into_iter()
+                    .zip(block.widths)
+                    .zip(block.heights.into_iter().map(|x| x + offset)),
+            );
+        }
+
+        self.blocks = steps
+            .chunks(size)
+            .map(|chunk| {
+                let mut block = Block {
+                    starts: chunk.iter().map(|((start, _), _)| *start).collect(),
+                    widths: chunk.iter().map(|((_, width), _)| *width).collect(),
+                    heights: chunk.iter().map(|(_, height)| *height).collect(),
+                    offset: 0,
+                    by_height: Vec::new(),
+                };
+                block.rebuild();
+                block
+            })
+            .collect();
+    }
+
+    /// The blocks holding steps that start in `from..to`, each with whether all of its steps do.
+    fn blocks_within(&self, from: i64, to: i64) -> impl Iterator<Item = (usize, bool)> + '_ {
+        let first = self.blocks.partition_point(|x| x.last() < from);
+
+        (first..self.blocks.len())
+            .take_while(move |b| self.blocks[*b].first() < to)
+            .map(move |b| (b, self.blocks[b].first() >= from && self.blocks[b].last() < to))
+    }
+
+    /// How wide the steps starting in `from..to` with heights of the given sign are together.
+    pub fn width_where(&self, from: i64, to: i64, sign: Sign) -> i64 {
+        self.blocks_within(from, to)
+            .map(|(b, whole)| {
+                let block = &self.blocks[b];
+                match whole {
+                    true => block.width_where(sign),
+                    false => (0..block.starts.len())
+                        .filter(|i| (from..to).contains(&block.starts[*i]))
+                        .filter(|i| sign.matches(block.heights[*i] + block.offset))
+                        .map(|i| block.widths[i])
+                        .sum(),
+                }
+            })
+            .sum()
+    }
+
+    /// Raises every step starting in `from..to` by `delta`.
+    pub fn add(&mut self, from: i64, to: i64, delta: i64) {
+        for (b, whole) in self.blocks_within(from, to).collect::<Vec<_>>() {
+            let block = &mut self.blocks[b];
+            if whole {
+                block.offset += delta;
+                continue;
+            }
+
+            for i in 0..block.starts.len() {
+                if (from..to).contains(&block.starts[i]) {
+                    block.heights[i] += delta;
+                }
+            }
+            block.rebuild();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_weigh_steps_by_sign_across_blocks() {
+        // Given
+        let mut imbalance = Imbalance::default();
+        (0..100).rev().for_each(|x| imbalance.add_step(x * 10));
+        imbalance.add(200, 600, 3);
+        imbalance.add(400, 800, -5);
+        (0..100).filter(|x| x % 3 == 1 && *x != 40).for_each(|x| imbalance.remove_step(x * 10));
+
+        // When
+        let heights = [150, 250, 450, 650, 990].map(|x| imbalance.height(x));
+        let widths = [Sign::AtLeastZero, Sign::AtMostZero].map(|x| imbalance.width_where(0, 990, x));
+
+        // Then
+        assert_eq!([0, 3, -2, -5, 0], heights);
+        assert_eq!([200 + 200 + 190, 200 + 200 + 200 + 190], widths);
+    }
+}
@@ -1,7 +1,15 @@
+mod imbalance;
+mod location_lists;
+mod order_statistic;
+mod radix;
+mod sorted_lists;
+
+pub use location_lists::{List, LocationLists};
+pub use sorted_lists::SortedLists;
 use std::iter::zip;
 use std::collections::HashMap;
 
-pub fn calculate_total_distance(input: String) -> i32 {
+pub fn calculate_total_distance(input: &str) -> i32 {
     let (left, right) = get_location_id_pairs(input.lines().collect());
 
     zip(left, right)
@@ -9,7 +17,7 @@ pub fn calculate_total_distance(input: String) -> i32 {
         .sum()
 }
 
-pub fn calculate_similarity_score(input: String) -> i32{
+pub fn calculate_similarity_score(input: &str) -> i32{
     let (left, right) = get_location_id_pairs(input.lines().collect());
 
     let mut hashmap = HashMap::new();
@@ -34,13 +42,11 @@ fn get_location_id_pairs(pairs: Vec<&str>) -> (Vec<i32>, Vec<i32>) {
 }
 
 fn extract_numeric_pair(x: &str) -> Option<(i32, i32)> {
-    let pair: Vec::<i32> = x.split(' ')
-        .filter_map(|y| y.parse::<i32>().ok())
-        .collect();
+    let mut integers = parsing::scan_whitespace_integers(x);
 
-    match pair.len() == 2 {
-        true => Some((pair[0], pair[1])),
-        false => None
+    match (integers.next(), integers.next(), integers.next()) {
+        (Some(Ok(a)), Some(Ok(b)), None) => Some((a, b)),
+        _ => None
     }
 }
 
@@ -58,7 +64,7 @@ mod tests {
     #[test]
     fn should_calculate_total_distance() {
         // when
-        let result = calculate_total_distance(INPUT.to_string());
+        let result = calculate_total_distance(INPUT);
 
         // then
         assert_eq!(result, 11);
@@ -67,9 +73,22 @@ mod tests {
     #[test]
     fn should_calculate_similarity_score() {
         // when
-        let result = calculate_similarity_score(INPUT.to_string());
+        let result = calculate_similarity_score(INPUT);
 
         // then
         assert_eq!(result, 31);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| calculate_total_distance(&parsing::normalise(&x)));
+
+        // Then
+        assert_eq!([11, 11], sums);
+    }
+}
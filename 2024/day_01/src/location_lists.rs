@@ -0,0 +1,270 @@
+use crate::imbalance::{Imbalance, Sign};
+use crate::order_statistic::OrderStatisticTree;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum List {
+    Left,
+    Right,
+}
+
+impl List {
+    fn index(&self) -> usize {
+        match self {
+            List::Left => 0,
+            List::Right => 1,
+        }
+    }
+}
+
+/// Both lists of location IDs, kept up to date as IDs are inserted and removed so the total
+/// distance and similarity score are always ready. Each change takes `O(√n log n)`.
+///
+/// Like `calculate_total_distance`, the distance pairs the lists' smallest IDs and leaves the
+/// largest IDs of a longer list unpaired. That distance is the area between the two lists' counts
+/// of paired IDs at or below each point, whose difference `imbalance` tracks, so a change only
+/// needs the area over the range it raises or lowers.
+#[derive(Debug, Default)]
+pub struct LocationLists {
+    sorted: [OrderStatisticTree; 2],
+    counts: [HashMap<i32, usize>; 2],
+    imbalance: Imbalance,
+    distance: i64,
+    similarity: i64,
+}
+
+impl From<&str> for LocationLists {
+    fn from(input: &str) -> LocationLists {
+        let mut lists = LocationLists::default();
+        input.lines().filter_map(crate::extract_numeric_pair).for_each(|(left, right)| {
+            lists.insert(List::Left, left);
+            lists.insert(List::Right, right);
+        });
+
+        lists
+    }
+}
+
+impl LocationLists {
+    pub fn len(&self, list: List) -> usize {
+        self.sorted[list.index()].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sorted.iter().all(OrderStatisticTree::is_empty)
+    }
+
+    pub fn total_distance(&self) -> i64 {
+        self.distance
+    }
+
+    pub fn similarity_score(&self) -> i64 {
+        self.similarity
+    }
+
+    pub fn insert(&mut self, list: List, id: i32) {
+        let (this, other) = (&self.sorted[list.index()], &self.sorted[1 - list.index()]);
+        let paired = this.len().min(other.len());
+
+        // Which paired IDs come and go, as the ID this list gains or the other loses and the ID
+        // this list loses or the other gains.
+        let change = match this.len() < other.len() {
+            // The lists gain a pair, this ID and the other list's smallest unpaired one.
+            true => other.nth(paired).map(|x| (id, x)),
+            // This ID pushes out this list's largest paired one if it is smaller.
+            false => paired.checked_sub(1).and_then(|x| this.nth(x)).filter(|x| id < *x).map(|x| (id, x)),
+        };
+
+        if !self.contains(id) {
+            self.imbalance.add_step(i64::from(id));
+        }
+        self.sorted[list.index()].insert(id);
+        *self.counts[list.index()].entry(id).or_default() += 1;
+        self.similarity += i64::from(id) * self.count(1 - list.index(), id);
+
+        if let Some((gained, lost)) = change {
+            self.shift(list, gained, lost);
+        }
+    }
+
+    /// Removes one copy of `id` from a list, returning whether it had one.
+    pub fn remove(&mut self, list: List, id: i32) -> bool {
+        if self.count(list.index(), id) == 0 {
+            return false;
+        }
+
+        let (this, other) = (&self.sorted[list.index()], &self.sorted[1 - list.index()]);
+        let paired = this.len().min(other.len());
+        let change = match this.len() > other.len() {
+            // A paired ID is replaced by this list's smallest unpaired one.
+            true => paired
+                .checked_sub(1)
+                .and_then(|x| this.nth(x))
+                .filter(|x| id <= *x)
+                .and_then(|_| this.nth(paired))
+                .map(|x| (x, id)),
+            // The lists lose a pair, this ID and the other list's largest paired one.
+            false => other.nth(paired - 1).map(|x| (x, id)),
+        };
+
+        self.sorted[list.index()].remove(id);
+        *self.counts[list.index()].entry(id).or_default() -= 1;
+        self.similarity -= i64::from(id) * self.count(1 - list.index(), id);
+
+        if let Some((gained, lost)) = change {
+            self.shift(list, gained, lost);
+        }
+        if !self.contains(id) {
+            self.imbalance.remove_step(i64::from(id));
+        }
+
+        true
+    }
+
+    fn count(&self, list: usize, id: i32) -> i64 {
+        self.counts[list].get(&id).map_or(0, |x| *x as i64)
+    }
+
+    fn contains(&self, id: i32) -> bool {
+        self.count(0, id) + self.count(1, id) > 0
+    }
+
+    /// Counts one more paired ID at or below every point from `gained` on and one fewer from
+    /// `lost` on, seen from `list`, and updates the distance by how much the area changes.
+    fn shift(&mut self, list: List, gained: i32, lost: i32) {
+        let (raised, lowered) = match list {
+            List::Left => (i64::from(gained), i64::from(lost)),
+            List::Right => (i64::from(lost), i64::from(gained)),
+        };
+
+        // Moving a step by one away from zero adds its width to the area, towards zero takes it.
+        if raised < lowered {
+            let away = self.imbalance.width_where(raised, lowered, Sign::AtLeastZero);
+            self.distance += 2 * away - (lowered - raised);
+            self.imbalance.add(raised, lowered, 1);
+        } else if lowered < raised {
+            let away = self.imbalance.width_where(lowered, raised, Sign::AtMostZero);
+            self.distance += 2 * away - (raised - lowered);
+            self.imbalance.add(lowered, raised, -1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_similarity_score, calculate_total_distance};
+    use proptest::prelude::*;
+
+    /// The lists as batch input, pairing them in sorted order and padding the shorter list with
+    /// `padding`.
+    fn as_input(left: &[i32], right: &[i32], padding: Option<i32>) -> String {
+        let (mut left, mut right) = (left.to_vec(), right.to_vec());
+        left.sort();
+        right.sort();
+        if let Some(padding) = padding {
+            let len = left.len().max(right.len());
+            left.resize(len, padding);
+            right.resize(len, padding);
+        }
+
+        left.iter().zip(&right).map(|(x, y)| format!("{}   {}\n", x, y)).collect()
+    }
+
+    #[test]
+    fn should_update_the_answers_as_ids_change() {
+        // Given
+        let mut lists = LocationLists::from("3   4\n4   3\n2   5\n1   3\n3   9\n3   3");
+        let answers = (lists.total_distance(), lists.similarity_score());
+
+        // When
+        lists.insert(List::Left, 9);
+        lists.insert(List::Right, 1);
+        let removed = [lists.remove(List::Right, 3), lists.remove(List::Right, 7)];
+
+        // Then
+        assert_eq!((11, 31), answers);
+        assert_eq!([true, false], removed);
+        // Left 1 2 3 3 3 4 9 pairs with right 1 3 3 4 5 9, leaving 9 on the left unpaired.
+        assert_eq!((9, 32), (lists.total_distance(), lists.similarity_score()));
+    }
+
+    fn held<'a>(left: &'a mut Vec<i32>, right: &'a mut Vec<i32>, list: List) -> &'a mut Vec<i32> {
+        match list {
+            List::Left => left,
+            List::Right => right,
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    enum Change {
+        Insert(List, i32),
+        /// Removes the ID at this index, wrapping around, so it is usually one the list has.
+        RemoveHeld(List, usize),
+        Remove(List, i32),
+    }
+
+    /// Mostly a few IDs that repeat, and some spread out enough to fill several blocks.
+    fn ids() -> impl Strategy<Value = i32> {
+        prop_oneof![3 => 1..60i32, 1 => 1..5000i32]
+    }
+
+    fn changes() -> impl Strategy<Value = Change> {
+        let list = prop_oneof![Just(List::Left), Just(List::Right)];
+
+        // Removing more than inserting shrinks the lists, leaving blocks to regroup.
+        prop_oneof![
+            1 => (list.clone(), ids()).prop_map(|(list, id)| Change::Insert(list, id)),
+            2 => (list.clone(), any::<usize>()).prop_map(|(list, i)| Change::RemoveHeld(list, i)),
+            1 => (list, ids()).prop_map(|(list, id)| Change::Remove(list, id)),
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(128))]
+
+        #[test]
+        fn should_match_the_batch_answers_after_every_change(
+            pairs in prop::collection::vec((ids(), ids()), 0..120),
+            changes in prop::collection::vec(changes(), 0..300),
+        ) {
+            let mut lists = LocationLists::default();
+            let (mut left, mut right) = (Vec::new(), Vec::new());
+            let inserts = pairs.iter().flat_map(|(x, y)| [Change::Insert(List::Left, *x), Change::Insert(List::Right, *y)]);
+
+            for change in inserts.chain(changes) {
+                let (list, removed) = match change {
+                    Change::Insert(list, id) => {
+                        lists.insert(list, id);
+                        held(&mut left, &mut right, list).push(id);
+                        (list, None)
+                    }
+                    Change::RemoveHeld(list, i) => {
+                        let ids = held(&mut left, &mut right, list);
+                        let id = match ids.is_empty() {
+                            true => 0,
+                            false => ids[i % ids.len()],
+                        };
+                        (list, Some(id))
+                    }
+                    Change::Remove(list, id) => (list, Some(id)),
+                };
+
+                if let Some(id) = removed {
+                    let ids = held(&mut left, &mut right, list);
+                    let position = ids.iter().position(|x| *x == id);
+                    prop_assert_eq!(position.is_some(), lists.remove(list, id));
+                    if let Some(position) = position {
+                        ids.swap_remove(position);
+                    }
+                }
+
+                let distance = calculate_total_distance(&as_input(&left, &right, None));
+                // No ID is 0, so padding with it leaves the score alone.
+                let similarity = calculate_similarity_score(&as_input(&left, &right, Some(0)));
+                prop_assert_eq!(i64::from(distance), lists.total_distance());
+                prop_assert_eq!(i64::from(similarity), lists.similarity_score());
+            }
+        }
+    }
+}
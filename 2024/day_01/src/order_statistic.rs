@@ -0,0 +1,170 @@
+/// A sorted multiset of IDs that can find the `k`th smallest in `O(log n)`, kept as a treap with
+/// one node per distinct ID.
+#[derive(Debug, Default)]
+pub struct OrderStatisticTree {
+    root: Option<Box<Node>>,
+    seed: u64,
+}
+
+#[derive(Debug)]
+struct Node {
+    value: i32,
+    count: usize,
+    /// How many IDs are under this node, counting repeats.
+    size: usize,
+    priority: u64,
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+impl Node {
+    fn update(&mut self) {
+        self.size = self.count + size(&self.left) + size(&self.right);
+    }
+}
+
+fn size(node: &Option<Box<Node>>) -> usize {
+    node.as_ref().map_or(0, |x| x.size)
+}
+
+/// Splits a tree into the IDs below `value` and the rest.
+fn split(node: Option<Box<Node>>, value: i32) -> (Option<Box<Node>>, Option<Box<Node>>) {
+    let Some(mut node) = node else {
+        return (None, None);
+    };
+
+    if node.value < value {
+        let (left, right) = split(node.right.take(), value);
+        node.right = left;
+        node.update();
+        (Some(node), right)
+    } else {
+        let (left, right) = split(node.left.take(), value);
+        node.left = right;
+        node.update();
+        (left, Some(node))
+    }
+}
+
+/// Joins two trees where every ID in `left` is below every ID in `right`.
+fn merge(left: Option<Box<Node>>, right: Option<Box<Node>>) -> Option<Box<Node>> {
+    match (left, right) {
+        (None, x) | (x, None) => x,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), Some(right));
+                left.update();
+                Some(left)
+            } else {
+                right.left = merge(Some(left), right.left.take());
+                right.update();
+                Some(right)
+            }
+        }
+    }
+}
+
+impl OrderStatisticTree {
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn insert(&mut self, value: i32) {
+        let priority = self.next_priority();
+        self.update(value, |node| match node {
+            Some(mut node) => {
+                node.count += 1;
+                Some(node)
+            }
+            None => Some(Box::new(Node {
+                value,
+                count: 1,
+                size: 1,
+                priority,
+                left: None,
+                right: None,
+            })),
+        });
+    }
+
+    /// Removes one copy of `value`, returning whether there was one.
+    pub fn remove(&mut self, value: i32) -> bool {
+        let mut removed = false;
+        self.update(value, |node| {
+            let mut node = node?;
+            removed = true;
+            node.count -= 1;
+            (node.count > 0).then_some(node)
+        });
+
+        removed
+    }
+
+    /// The `k`th smallest ID, counting from zero and including repeats.
+    pub fn nth(&self, mut k: usize) -> Option<i32> {
+        let mut node = self.root.as_ref()?;
+
+        loop {
+            let left = size(&node.left);
+            if k < left {
+                node = node.left.as_ref()?;
+            } else if k < left + node.count {
+                return Some(node.value);
+            } else {
+                k -= left + node.count;
+                node = node.right.as_ref()?;
+            }
+        }
+    }
+
+    /// Replaces the node for `value`, or the lack of one, with whatever `change` gives back.
+    fn update(&mut self, value: i32, change: impl FnOnce(Option<Box<Node>>) -> Option<Box<Node>>) {
+        let (below, rest) = split(self.root.take(), value);
+        let (node, above) = match value.checked_add(1) {
+            Some(next) => split(rest, next),
+            None => (rest, None),
+        };
+
+        let node = change(node).map(|mut x| {
+            x.update();
+            x
+        });
+        self.root = merge(merge(below, node), above);
+    }
+
+    /// A xorshift sequence, so the tree is balanced whatever order IDs arrive in.
+    fn next_priority(&mut self) -> u64 {
+        if self.seed == 0 {
+            self.seed = 0x9E37_79B9_7F4A_7C15;
+        }
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+
+        self.seed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_the_kth_smallest_id_with_repeats() {
+        // Given
+        let mut tree = OrderStatisticTree::default();
+        [5, 3, 9, 3, i32::MAX, i32::MIN, 5, 5].into_iter().for_each(|x| tree.insert(x));
+
+        // When
+        let removed = [tree.remove(5), tree.remove(4), tree.remove(i32::MAX)];
+        let sorted = (0..tree.len() + 1).map(|k| tree.nth(k)).collect::<Vec<_>>();
+
+        // Then
+        assert_eq!([true, false, true], removed);
+        assert_eq!(vec![Some(i32::MIN), Some(3), Some(3), Some(5), Some(5), Some(9), None], sorted);
+    }
+}
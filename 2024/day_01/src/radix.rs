@@ -0,0 +1,194 @@
+/// Sorts with a least significant digit radix sort. When the values span a range no bigger than
+/// their count, as five digit IDs do, a single digit covers the whole range and the sort is one
+/// counting pass; otherwise it takes passes of `DIGIT_BITS` bits, skipping digits every value
+/// shares.
+pub fn sort(values: &mut [u32]) {
+    let Some((min, max)) = bounds(values) else {
+        return;
+    };
+    let range = (max - min) as usize + 1;
+
+    match range <= values.len().max(1 << DIGIT_BITS) {
+        true => counting_sort(values, min, range),
+        false => sort_low_bits(values, &mut vec![0; values.len()], u32::BITS),
+    }
+}
+
+/// Splits the values by their top eight differing bits, then sorts the buckets on the rayon pool.
+#[cfg(feature = "parallel")]
+pub fn par_sort(values: &mut [u32]) {
+    use rayon::prelude::*;
+
+    let Some(first) = values.first().copied() else {
+        return;
+    };
+    let differing = values
+        .par_iter()
+        .map(|x| x ^ first)
+        .reduce(|| 0, |a, b| a | b);
+    if differing == 0 {
+        return;
+    }
+    let shift = (u32::BITS - differing.leading_zeros()).saturating_sub(8);
+
+    let counts = histogram(values, shift, 0xff);
+    let mut scratch = vec![0; values.len()];
+    scatter(values, &mut scratch, shift, 0xff, &counts);
+
+    let mut buckets = Vec::with_capacity(counts.len());
+    let mut rest = scratch.as_mut_slice();
+    for count in counts {
+        let (bucket, tail) = rest.split_at_mut(count);
+        buckets.push(bucket);
+        rest = tail;
+    }
+    buckets.into_par_iter().for_each(sort);
+
+    values
+        .par_iter_mut()
+        .zip(scratch.par_iter())
+        .for_each(|(x, y)| *x = *y);
+}
+
+const DIGIT_BITS: u32 = 11;
+
+fn bounds(values: &[u32]) -> Option<(u32, u32)> {
+    let first = *values.first()?;
+
+    Some(
+        values
+            .iter()
+            .fold((first, first), |(min, max), x| (min.min(*x), max.max(*x))),
+    )
+}
+
+/// Counts each value in `min..min + range` and writes them back out in order.
+fn counting_sort(values: &mut [u32], min: u32, range: usize) {
+    let mut counts = vec![0_usize; range];
+    values.iter().for_each(|x| counts[(x - min) as usize] += 1);
+
+    let mut rest = &mut values[..];
+    for (offset, count) in counts.into_iter().enumerate() {
+        let (run, tail) = rest.split_at_mut(count);
+        run.fill(min + offset as u32);
+        rest = tail;
+    }
+}
+
+/// Sorts by the lowest `bits` bits, leaving values that only differ above them in their order.
+fn sort_low_bits(values: &mut [u32], scratch: &mut [u32], bits: u32) {
+    let (mut from, mut to) = (values, scratch);
+    let mut in_scratch = false;
+    let mask = (1 << DIGIT_BITS) - 1;
+
+    for shift in (0..bits).step_by(DIGIT_BITS as usize) {
+        let counts = histogram(from, shift, mask);
+        if counts.contains(&from.len()) {
+            continue;
+        }
+        scatter(from, to, shift, mask, &counts);
+        std::mem::swap(&mut from, &mut to);
+        in_scratch = !in_scratch;
+    }
+
+    // After an odd number of passes the sorted values are in the scratch buffer.
+    if in_scratch {
+        to.copy_from_slice(from);
+    }
+}
+
+fn digit(value: u32, shift: u32, mask: u32) -> usize {
+    ((value >> shift) & mask) as usize
+}
+
+fn histogram(values: &[u32], shift: u32, mask: u32) -> Vec<usize> {
+    let mut counts = vec![0; mask as usize + 1];
+    values
+        .iter()
+        .for_each(|x| counts[digit(*x, shift, mask)] += 1);
+
+    counts
+}
+
+/// Moves each value into `to` by its digit, given how many values have each digit.
+fn scatter(from: &[u32], to: &mut [u32], shift: u32, mask: u32, counts: &[usize]) {
+    let mut offsets = Vec::with_capacity(counts.len());
+    let mut total = 0;
+    for count in counts {
+        offsets.push(total);
+        total += count;
+    }
+
+    for value in from {
+        let offset = &mut offsets[digit(*value, shift, mask)];
+        to[*offset] = *value;
+        *offset += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> Vec<u32> {
+        let mut seed = 0x2545_F491_4F6C_DD1D_u64;
+        (0..5000)
+            .map(|i| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                // Mixes five digit IDs with ones that use every byte.
+                match i % 3 {
+                    0 => seed as u32,
+                    _ => 10_000 + (seed % 90_000) as u32,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_sort_like_the_standard_library() {
+        // Given
+        let mut expected = values();
+        expected.sort_unstable();
+
+        // When
+        let sorted = [
+            values(),
+            values().into_iter().map(|x| x % 100_000).collect(),
+            vec![7, u32::MAX, 7, 0],
+            vec![],
+        ]
+        .map(|mut x| {
+            sort(&mut x);
+            x
+        });
+
+        // Then
+        assert_eq!(expected, sorted[0]);
+        assert!(sorted[1].is_sorted());
+        assert_eq!(vec![0, 7, 7, u32::MAX], sorted[2]);
+        assert!(sorted[3].is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn should_sort_in_parallel_like_the_standard_library() {
+        // Given
+        let mut expected = values();
+        expected.sort_unstable();
+        let mut small = values()
+            .into_iter()
+            .map(|x| x % 100_000)
+            .collect::<Vec<_>>();
+
+        // When
+        let mut sorted = values();
+        par_sort(&mut sorted);
+        par_sort(&mut small);
+
+        // Then
+        assert_eq!(expected, sorted);
+        assert!(small.is_sorted());
+    }
+}
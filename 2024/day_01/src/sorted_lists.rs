@@ -0,0 +1,150 @@
+use crate::radix;
+use parsing::ParseError;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Both lists parsed straight into sorted vectors, for generated inputs with millions of lines.
+/// Unlike `calculate_total_distance` it reads the whitespace separated items of the input as
+/// alternating left and right IDs rather than checking each line holds a pair, and any item that
+/// isn't an ID fitting a `u32` is an error. The total distance is 64-bit, which holds the largest distance on 2^32 lines,
+/// and the similarity score is 128-bit, since an ID repeated on every line scores the square of
+/// the line count.
+#[derive(Debug)]
+pub struct SortedLists {
+    left: Vec<u32>,
+    right: Vec<u32>,
+}
+
+impl FromStr for SortedLists {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<SortedLists, ParseError> {
+        // Lines are usually all as long as the first, which saves counting them.
+        let line = input.find('\n').map_or(input.len(), |x| x + 1).max(1);
+        let lines = input.len() / line + 1;
+        let (mut left, mut right) = (Vec::with_capacity(lines), Vec::with_capacity(lines));
+
+        let mut integers = parsing::scan_whitespace_integers::<u32>(input);
+        while let (Some(x), Some(y)) = (integers.next(), integers.next()) {
+            left.push(x?);
+            right.push(y?);
+        }
+        sort_both(&mut left, &mut right);
+
+        Ok(SortedLists { left, right })
+    }
+}
+
+#[cfg(feature = "parallel")]
+fn sort_both(left: &mut [u32], right: &mut [u32]) {
+    rayon::join(|| radix::par_sort(left), || radix::par_sort(right));
+}
+
+#[cfg(not(feature = "parallel"))]
+fn sort_both(left: &mut [u32], right: &mut [u32]) {
+    radix::sort(left);
+    radix::sort(right);
+}
+
+impl SortedLists {
+    pub fn total_distance(&self) -> u64 {
+        self.left
+            .iter()
+            .zip(&self.right)
+            .map(|(x, y)| u64::from(x.abs_diff(*y)))
+            .sum()
+    }
+
+    /// Walks both sorted lists together, scoring each ID by how often it is in each.
+    pub fn similarity_score(&self) -> u128 {
+        let (left, right) = (&self.left, &self.right);
+        let (mut i, mut j) = (0, 0);
+        let mut score = 0;
+
+        while i < left.len() && j < right.len() {
+            match left[i].cmp(&right[j]) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    let id = left[i];
+                    let in_left = left[i..].iter().take_while(|x| **x == id).count();
+                    let in_right = right[j..].iter().take_while(|x| **x == id).count();
+                    score += u128::from(id) * in_left as u128 * in_right as u128;
+                    i += in_left;
+                    j += in_right;
+                }
+            }
+        }
+
+        score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_similarity_score, calculate_total_distance};
+    use parsing::Span;
+
+    #[test]
+    fn should_match_the_batch_answers() {
+        // Given
+        let input = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+        let wide = (1..300)
+            .map(|x| {
+                format!(
+                    "{}   {}\n",
+                    x * 7919 % 1000 + 10_000,
+                    x * 104_729 % 997 + 10_000
+                )
+            })
+            .collect::<String>();
+
+        // When
+        let lists = [input, &wide].map(|x| x.parse::<SortedLists>().unwrap());
+
+        // Then
+        assert_eq!(
+            (11, 31),
+            (lists[0].total_distance(), lists[0].similarity_score())
+        );
+        assert_eq!(
+            calculate_total_distance(&wide) as u64,
+            lists[1].total_distance()
+        );
+        assert_eq!(
+            calculate_similarity_score(&wide) as u128,
+            lists[1].similarity_score()
+        );
+    }
+
+    #[test]
+    fn should_total_beyond_32_bits() {
+        // Given
+        let input =
+            format!("{0}   {0}\n", u32::MAX).repeat(3) + &format!("0   {}\n", u32::MAX).repeat(2);
+
+        // When
+        let lists = input.parse::<SortedLists>().unwrap();
+        let too_big = "1   4294967296\n".parse::<SortedLists>();
+        let corrupt = "3   4\n4   x3\n".parse::<SortedLists>();
+
+        // Then
+        assert_eq!(Some(Span::new(4, 14)), too_big.err().map(|x| x.span));
+        assert_eq!(Some(Span::new(10, 12)), corrupt.err().map(|x| x.span));
+        assert_eq!(u64::from(u32::MAX) * 2, lists.total_distance());
+        assert_eq!(u128::from(u32::MAX) * 15, lists.similarity_score());
+    }
+
+    #[test]
+    fn should_score_beyond_64_bits() {
+        // Given
+        let input = format!("{0}   {0}\n", u32::MAX).repeat(70_000);
+
+        // When
+        let lists = input.parse::<SortedLists>().unwrap();
+
+        // Then
+        assert_eq!(u128::from(u32::MAX) * 70_000 * 70_000, lists.similarity_score());
+    }
+}
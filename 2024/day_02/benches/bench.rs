@@ -0,0 +1,21 @@
+use divan::AllocProfiler;
+use y2024_day_02::{get_dampened_safe_report_count, get_safe_level_report_count};
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    get_safe_level_report_count(
+        &divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap())
+    ).unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    get_dampened_safe_report_count(
+        &divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap())
+    ).unwrap();
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+fn main() -> eyre::Result<()> {
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
+
+    let safe_levels = y2024_day_02::get_safe_level_report_count(&input)?;
+
+    println!("Part 1: {}", safe_levels);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,144 @@
+use crate::ReportPolicy;
+
+/// The fewest levels, at most `tolerance` of them, whose removal leaves the report safe, by index
+/// in ascending order. An already safe report needs none removed, so gives an empty list.
+///
+/// Between two levels that are kept next to each other at most `tolerance` are removed, so each
+/// level only needs checking against the `tolerance + 1` levels before it, once per direction.
+pub fn dampen(report: &[i32], tolerance: usize) -> Option<Vec<usize>> {
+    ReportPolicy::default().dampen(report, tolerance)
+}
+
+impl ReportPolicy {
+    /// Like [`dampen`], for the report being safe by this policy.
+    pub fn dampen(&self, report: &[i32], tolerance: usize) -> Option<Vec<usize>> {
+        if report.is_empty() {
+            return Some(Vec::new());
+        }
+
+        self.directions()
+            .iter()
+            .filter_map(|direction| dampen_towards(self, report, tolerance, *direction))
+            .min_by_key(Vec::len)
+    }
+}
+
+fn dampen_towards(policy: &ReportPolicy, report: &[i32], tolerance: usize, direction: i32) -> Option<Vec<usize>> {
+    let n = report.len();
+    // The fewest removals before each level that leave it the last of a safe run, and the kept
+    // level before it.
+    let mut removals = vec![usize::MAX; n];
+    let mut previous = vec![None; n];
+
+    for i in 0..n {
+        // Dropping every level before this one.
+        if i <= tolerance {
+            removals[i] = i;
+        }
+
+        for j in (i.saturating_sub(tolerance + 1)..i).rev() {
+            let skipped = i - j - 1;
+            if removals[j] == usize::MAX || !policy.allows(report[j], report[i], direction) {
+                continue;
+            }
+            if removals[j] + skipped < removals[i] && removals[j] + skipped <= tolerance {
+                removals[i] = removals[j] + skipped;
+                previous[i] = Some(j);
+            }
+        }
+    }
+
+    let last = (n.saturating_sub(tolerance + 1)..n)
+        .filter(|i| removals[*i] != usize::MAX)
+        .min_by_key(|i| removals[*i] + (n - 1 - i))
+        .filter(|i| removals[*i] + (n - 1 - i) <= tolerance)?;
+
+    let mut kept = vec![false; n];
+    let mut level = Some(last);
+    while let Some(i) = level {
+        kept[i] = true;
+        level = previous[i];
+    }
+
+    Some((0..n).filter(|i| !kept[*i]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_the_levels_to_remove() {
+        // Given
+        let reports = [
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![1, 3, 6, 7, 9, 20, 8],
+        ];
+
+        // When
+        let once = reports.clone().map(|x| dampen(&x, 1));
+        let twice = reports.map(|x| dampen(&x, 2));
+
+        // Then
+        assert_eq!([Some(vec![]), None, Some(vec![1]), Some(vec![2]), None], once);
+        assert_eq!(Some(vec![5, 6]), twice[4]);
+        assert_eq!(Some(vec![0, 1]), twice[1]);
+    }
+
+    /// Whether removing the levels at `removed` leaves a safe report, by the puzzle's rule.
+    fn is_safe_without(report: &[i32], removed: &[usize]) -> bool {
+        let kept = (0..report.len()).filter(|i| !removed.contains(i)).map(|i| report[i]).collect::<Vec<_>>();
+
+        crate::is_report_safe(&kept)
+    }
+
+    /// Tries every way of removing up to `tolerance` levels, for the fewest that work.
+    fn brute_force(report: &[i32], tolerance: usize) -> Option<usize> {
+        (0..=tolerance).find(|count| {
+            (0_u32..1 << report.len())
+                .filter(|x| x.count_ones() as usize == *count)
+                .any(|x| is_safe_without(report, &(0..report.len()).filter(|i| x & (1 << i) != 0).collect::<Vec<_>>()))
+        })
+    }
+
+    #[test]
+    fn should_match_the_brute_force() {
+        // Given
+        let mut seed = 0x2545_F491_4F6C_DD1D_u64;
+        let mut next = |bound: u64| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed % bound
+        };
+        let reports = (0..2000)
+            .map(|_| {
+                let len = 3 + next(7) as usize;
+                let mut level = 20;
+                (0..len)
+                    .map(|_| {
+                        level += next(9) as i32 - 3;
+                        level
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        for report in &reports {
+            for tolerance in 0..=3 {
+                // When
+                let removed = dampen(report, tolerance);
+
+                // Then
+                assert_eq!(brute_force(report, tolerance), removed.as_ref().map(Vec::len), "{:?}", report);
+                if let Some(removed) = removed {
+                    assert!(is_safe_without(report, &removed), "{:?} without {:?}", report, removed);
+                }
+            }
+            assert_eq!(crate::can_report_be_dampened(report) || crate::is_report_safe(report), dampen(report, 1).is_some());
+        }
+    }
+}
@@ -0,0 +1,167 @@
+mod dampener;
+mod policy;
+
+pub use dampener::dampen;
+pub use policy::{Direction, ReportPolicy, Verdict, Violation, ViolationKind};
+use parsing::{ParseError, Segment, Separator};
+
+pub fn get_safe_level_report_count(input: &str) -> Result<usize, ParseError> {
+    let level_reports = get_level_reports(input)?;
+
+    let safe_levels = level_reports
+        .into_iter()
+        .filter(|x| is_report_safe(x))
+        .collect::<Vec<_>>()
+        .len();
+
+    Ok(safe_levels)
+}
+
+pub fn get_dampened_safe_report_count(input: &str) -> Result<usize, ParseError> {
+    get_tolerant_safe_report_count(input, 1)
+}
+
+/// Counts the reports made safe by removing at most `tolerance` levels.
+pub fn get_tolerant_safe_report_count(input: &str, tolerance: usize) -> Result<usize, ParseError> {
+    Ok(get_level_reports(input)?
+        .iter()
+        .filter(|x| dampen(x, tolerance).is_some())
+        .count())
+}
+
+/// The levels of each line. Lines without any, such as a doubled trailing newline, aren't
+/// reports, so they are skipped rather than counted as safe.
+fn get_level_reports(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    Segment::new(input).lines()
+        .map(|x| x.integers::<i32>(Separator::Whitespace))
+        .filter(|x| x.as_ref().map_or(true, |report| !report.is_empty()))
+        .collect()
+}
+
+fn is_report_safe(report: &Vec<i32>) -> bool {
+    ReportPolicy::default().is_safe(report)
+}
+
+/// Tries removing each level in turn, which [`dampen`] is checked against.
+pub fn can_report_be_dampened(report: &Vec<i32>) -> bool {
+    for n in 0..report.len() {
+        let subset : Vec<i32> = report.iter()
+            .enumerate()
+            .filter(|(i, _)| i!= &n)
+            .map(|(_, v)| v.clone())
+            .collect();
+
+        if is_report_safe(&subset){
+            return true;
+        }
+    }
+
+    false
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parsing::{ParseErrorKind, Span};
+
+    #[test]
+    fn should_get_level_reports() {
+        // given
+        let input = "7 6 4 2 1
+        1 2 7 8 9
+        9 7 6 2 1
+        1 3 2 4 5
+        8 6 4 4 1
+        1 3 6 7 9";
+
+        let expected = vec![
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![9, 7, 6, 2, 1],
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![1, 3, 6, 7, 9]
+        ];
+
+        // when
+        let output = get_level_reports(input).unwrap();
+
+        // then
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn should_get_safe_level_report_count() {
+        // given
+        let input = "7 6 4 2 1
+    1 2 7 8 9
+    9 7 6 2 1
+    1 3 2 4 5
+    8 6 4 4 1
+    1 3 6 7 9";
+
+        // when
+        let safe_levels = get_safe_level_report_count(input).unwrap();
+
+        // then
+        assert_eq!(safe_levels, 2);
+    }
+
+    #[test]
+    fn should_get_dampened_safe_report_count() {
+        // given
+        let input = "7 6 4 2 1
+        1 2 7 8 9
+        9 7 6 2 1
+        1 3 2 4 5
+        8 6 4 4 1
+        1 3 6 7 9";
+
+        // when
+        let safe_levels = get_dampened_safe_report_count(input).unwrap();
+
+        // then
+        assert_eq!(safe_levels, 4);
+    }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let input = "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9";
+        let crlf = input.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| get_safe_level_report_count(&parsing::normalise(&x)).unwrap());
+
+        // Then
+        assert_eq!([2, 2], sums);
+    }
+
+    #[test]
+    fn should_reject_levels_that_are_not_integers() {
+        // Given
+        let input = "7 6 4 2 1\n7 6 a 2 1\n";
+
+        // When
+        let error = get_safe_level_report_count(input).unwrap_err();
+
+        // Then
+        assert_eq!(ParseErrorKind::InvalidInteger, error.kind);
+        assert_eq!(Span::new(14, 15), error.span);
+    }
+
+    #[test]
+    fn should_skip_blank_lines() {
+        // Given
+        let input = "7 6 4 2 1\n\n1 3 2 4 5\n8 6 4 4 1\n\n";
+
+        // When
+        let counts = [get_safe_level_report_count(input), get_dampened_safe_report_count(input)];
+
+        // Then
+        assert_eq!(3, get_level_reports(input).unwrap().len());
+        assert_eq!([Ok(1), Ok(3)], counts);
+    }
+}
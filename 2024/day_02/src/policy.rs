@@ -0,0 +1,206 @@
+use std::cmp::Ordering;
+
+/// Which way a report's levels may go.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    /// Either way, as long as every step goes the same way as the first.
+    Either,
+}
+
+/// What makes a report safe. The default is the puzzle's rule: levels only increase or only
+/// decrease, by 1 to 3 at a time.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ReportPolicy {
+    pub min_step: i32,
+    pub max_step: i32,
+    pub direction: Direction,
+    /// Whether neighbouring levels may be equal, which then sets no direction.
+    pub allow_equal: bool,
+}
+
+impl Default for ReportPolicy {
+    fn default() -> ReportPolicy {
+        ReportPolicy { min_step: 1, max_step: 3, direction: Direction::Either, allow_equal: false }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ViolationKind {
+    Equal,
+    TooSmall,
+    TooLarge,
+    WrongDirection,
+}
+
+/// A pair of neighbouring levels breaking the policy, by the index of the first.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Violation {
+    pub index: usize,
+    pub kind: ViolationKind,
+    pub pair: (i32, i32),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Safe,
+    Unsafe(Vec<Violation>),
+}
+
+impl ReportPolicy {
+    pub fn check(&self, report: &[i32]) -> Verdict {
+        // The first step that goes anywhere sets the direction when either is allowed.
+        let expected = match self.direction {
+            Direction::Increasing => Ordering::Less,
+            Direction::Decreasing => Ordering::Greater,
+            Direction::Either => report
+                .windows(2)
+                .map(|pair| pair[0].cmp(&pair[1]))
+                .find(|x| x.is_ne())
+                .unwrap_or(Ordering::Equal),
+        };
+
+        let violations = report
+            .windows(2)
+            .enumerate()
+            .flat_map(|(index, pair)| {
+                let violation = |kind| Violation { index, kind, pair: (pair[0], pair[1]) };
+                let step = pair[0].abs_diff(pair[1]) as i64;
+
+                match pair[0].cmp(&pair[1]) {
+                    Ordering::Equal => (!self.allow_equal).then(|| violation(ViolationKind::Equal)).into_iter().collect(),
+                    order => [
+                        (order != expected).then(|| violation(ViolationKind::WrongDirection)),
+                        (step < i64::from(self.min_step)).then(|| violation(ViolationKind::TooSmall)),
+                        (step > i64::from(self.max_step)).then(|| violation(ViolationKind::TooLarge)),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match violations.is_empty() {
+            true => Verdict::Safe,
+            false => Verdict::Unsafe(violations),
+        }
+    }
+
+    pub fn is_safe(&self, report: &[i32]) -> bool {
+        self.check(report) == Verdict::Safe
+    }
+
+    /// Whether `from` may be followed by `to` in a report going up for a `direction` of 1 and
+    /// down for -1.
+    pub(crate) fn allows(&self, from: i32, to: i32, direction: i32) -> bool {
+        let step = (i64::from(to) - i64::from(from)) * i64::from(direction);
+
+        match step {
+            0 => self.allow_equal,
+            _ => step > 0 && (i64::from(self.min_step)..=i64::from(self.max_step)).contains(&step),
+        }
+    }
+
+    /// The directions `allows` should be tried in.
+    pub(crate) fn directions(&self) -> &'static [i32] {
+        match self.direction {
+            Direction::Increasing => &[1],
+            Direction::Decreasing => &[-1],
+            Direction::Either => &[1, -1],
+        }
+    }
+
+    /// The report on one line, carets under the levels of each pair that breaks the policy, then
+    /// what each pair breaks.
+    pub fn render(&self, report: &[i32]) -> String {
+        let levels = report.iter().map(i32::to_string).collect::<Vec<_>>();
+        let mut line = levels.join(" ");
+        let Verdict::Unsafe(violations) = self.check(report) else {
+            return line + "\nsafe";
+        };
+
+        let starts = levels
+            .iter()
+            .scan(0, |start, level| {
+                let this = *start;
+                *start += level.len() + 1;
+                Some(this)
+            })
+            .collect::<Vec<_>>();
+        let mut marks = vec![b' '; line.len()];
+        for violation in &violations {
+            let end = starts[violation.index + 1] + levels[violation.index + 1].len();
+            marks[starts[violation.index]..end].fill(b'^');
+        }
+
+        line.push('\n');
+        line.push_str(String::from_utf8_lossy(&marks).trim_end());
+        for Violation { index, kind, pair } in violations {
+            let reason = match kind {
+                ViolationKind::Equal => "equal".to_string(),
+                ViolationKind::TooSmall => format!("step under {}", self.min_step),
+                ViolationKind::TooLarge => format!("step over {}", self.max_step),
+                ViolationKind::WrongDirection => "wrong direction".to_string(),
+            };
+            line.push_str(&format!("\n{}: {} {}: {}", index, pair.0, pair.1, reason));
+        }
+
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_list_each_pair_breaking_the_policy() {
+        // Given
+        let policy = ReportPolicy::default();
+        let reports: [&[i32]; 4] = [&[7, 6, 4, 2, 1], &[1, 3, 2, 4, 5], &[8, 6, 4, 4, 1], &[5]];
+
+        // When
+        let verdicts = reports.map(|x| policy.check(x));
+
+        // Then
+        assert_eq!(Verdict::Safe, verdicts[0]);
+        assert_eq!(Verdict::Unsafe(vec![Violation { index: 1, kind: ViolationKind::WrongDirection, pair: (3, 2) }]), verdicts[1]);
+        assert_eq!(Verdict::Unsafe(vec![Violation { index: 2, kind: ViolationKind::Equal, pair: (4, 4) }]), verdicts[2]);
+        assert_eq!(Verdict::Safe, verdicts[3]);
+    }
+
+    #[test]
+    fn should_follow_a_configured_policy() {
+        // Given
+        let policy = ReportPolicy { min_step: 2, max_step: 10, direction: Direction::Decreasing, allow_equal: true };
+
+        // When
+        let verdicts = [[9, 9, 7, 1], [9, 8, 8, 20]].map(|x| policy.check(&x));
+
+        // Then
+        assert_eq!(Verdict::Safe, verdicts[0]);
+        assert_eq!(
+            Verdict::Unsafe(vec![
+                Violation { index: 0, kind: ViolationKind::TooSmall, pair: (9, 8) },
+                Violation { index: 2, kind: ViolationKind::WrongDirection, pair: (8, 20) },
+                Violation { index: 2, kind: ViolationKind::TooLarge, pair: (8, 20) },
+            ]),
+            verdicts[1]
+        );
+    }
+
+    #[test]
+    fn should_mark_the_bad_positions() {
+        // Given
+        let policy = ReportPolicy::default();
+
+        // When
+        let rendered = [&[1, 2, 7, 8, 9][..], &[10, 13, 13, 12]].map(|x| policy.render(x));
+
+        // Then
+        assert_eq!("1 2 7 8 9\n  ^^^\n1: 2 7: step over 3", rendered[0]);
+        assert_eq!("10 13 13 12\n   ^^^^^^^^\n1: 13 13: equal\n2: 13 12: wrong direction", rendered[1]);
+    }
+}
@@ -0,0 +1,28 @@
+use divan::AllocProfiler;
+use y2024_day_03::{part_1, part_2, Stream};
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    part_1(
+        &divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap())
+    ).unwrap();
+}
+
+#[divan::bench]
+fn part2() {
+    part_2(
+        &divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap())
+    ).unwrap();
+}
+
+/// Both parts at once, reading the input in 4 KiB chunks.
+#[divan::bench]
+fn streamed() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    Stream::new(input.as_bytes(), 4096).last().unwrap().unwrap();
+}
@@ -0,0 +1,83 @@
+use crate::lexer::Instruction;
+
+/// What a call to a registered instruction gives, from its arguments.
+pub type Call = fn(&[i64]) -> i64;
+
+/// Runs instructions, adding up what each enabled one gives. `don't()` disables the ones after
+/// it and `do()` enables them again, unless the interpreter ignores them.
+#[derive(Debug, Clone)]
+pub struct Interpreter {
+    enabled: bool,
+    switches: bool,
+    total: i64,
+    calls: Vec<(&'static str, Call)>,
+}
+
+impl Default for Interpreter {
+    fn default() -> Interpreter {
+        Interpreter { enabled: true, switches: true, total: 0, calls: Vec::new() }
+    }
+}
+
+impl Interpreter {
+    /// Runs every instruction as enabled.
+    pub fn ignoring_switches() -> Interpreter {
+        Interpreter { switches: false, ..Interpreter::default() }
+    }
+
+    /// Gives each call to a registered instruction with this name a value from its arguments.
+    /// Calls to instructions without one give nothing.
+    pub fn with_call(mut self, name: &'static str, call: Call) -> Interpreter {
+        self.calls.push((name, call));
+        self
+    }
+
+    pub fn total(&self) -> i64 {
+        self.total
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn step(&mut self, instruction: &Instruction) {
+        match instruction {
+            Instruction::Do => self.enabled = true,
+            Instruction::Dont => self.enabled = !self.switches,
+            _ if !self.enabled => {}
+            Instruction::Mul(a, b) => self.total += a * b,
+            Instruction::Call(name, arguments) => {
+                if let Some((_, call)) = self.calls.iter().find(|(x, _)| x == name) {
+                    self.total += call(arguments);
+                }
+            }
+        }
+    }
+
+    /// Runs the instructions, giving the total.
+    pub fn run(mut self, instructions: impl IntoIterator<Item = Instruction>) -> i64 {
+        instructions.into_iter().for_each(|x| self.step(&x));
+        self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::{Lexer, Syntax};
+
+    #[test]
+    fn should_add_up_enabled_instructions() {
+        // Given
+        let lexer = Lexer::default().register(Syntax { name: "add", min_arguments: 2, max_arguments: 4 });
+        let input = "mul(2,3)add(1,2,3)don't()mul(5,5)add(9,9)do()add(4,4)sub(1,1)";
+        let instructions = lexer.tokens(input).map(|x| x.instruction).collect::<Vec<_>>();
+        let interpreters = [Interpreter::default(), Interpreter::ignoring_switches()];
+
+        // When
+        let totals = interpreters.map(|x| x.with_call("add", |arguments| arguments.iter().sum()).run(instructions.clone()));
+
+        // Then
+        assert_eq!([6 + 6 + 8, 6 + 6 + 25 + 18 + 8], totals);
+    }
+}
@@ -0,0 +1,202 @@
+use std::ops::Range;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Instruction {
+    Mul(i64, i64),
+    Do,
+    Dont,
+    /// A registered instruction, by its name, with its arguments.
+    Call(&'static str, Vec<i64>),
+}
+
+/// An instruction found in the input, with the bytes it was written in.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Token {
+    pub instruction: Instruction,
+    pub span: Range<usize>,
+}
+
+/// How an instruction is written: its name, then in brackets between `min_arguments` and
+/// `max_arguments` comma separated numbers of one to three digits.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Syntax {
+    pub name: &'static str,
+    pub min_arguments: usize,
+    pub max_arguments: usize,
+}
+
+impl Syntax {
+    pub fn new(name: &'static str, arguments: usize) -> Syntax {
+        Syntax { name, min_arguments: arguments, max_arguments: arguments }
+    }
+}
+
+const MAX_DIGITS: usize = 3;
+
+/// What the input holds from some position on.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Lexed {
+    /// An instruction, and where it ends.
+    Token(Instruction, usize),
+    /// No instruction starts there.
+    Nothing,
+    /// The input ends partway through what could still be an instruction.
+    Incomplete,
+}
+
+/// Finds the instructions in corrupted memory, `mul`, `do` and `don't` and any registered.
+#[derive(Debug, Clone)]
+pub struct Lexer {
+    syntaxes: Vec<Syntax>,
+}
+
+impl Default for Lexer {
+    fn default() -> Lexer {
+        Lexer { syntaxes: vec![Syntax::new("mul", 2), Syntax::new("do", 0), Syntax::new("don't", 0)] }
+    }
+}
+
+impl Lexer {
+    /// Recognises another instruction, or changes how many arguments one with the same name
+    /// takes. Only `mul` with two arguments is a [`Instruction::Mul`], otherwise it is a call.
+    pub fn register(mut self, syntax: Syntax) -> Lexer {
+        match self.syntaxes.iter_mut().find(|x| x.name == syntax.name) {
+            Some(existing) => *existing = syntax,
+            None => self.syntaxes.push(syntax),
+        }
+
+        self
+    }
+
+    /// The instructions in the input, in one pass over it.
+    pub fn tokens<'a>(&'a self, input: &'a str) -> impl Iterator<Item = Token> + 'a {
+        let input = input.as_bytes();
+        let mut at = 0;
+
+        std::iter::from_fn(move || {
+            while at < input.len() {
+                let start = at;
+                match self.lex_at(input, start) {
+                    Lexed::Token(instruction, end) => {
+                        at = end;
+                        return Some(Token { instruction, span: start..end });
+                    }
+                    Lexed::Nothing | Lexed::Incomplete => at += 1,
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Reads the instruction starting at `at`, if there is one.
+    pub fn lex_at(&self, input: &[u8], at: usize) -> Lexed {
+        let rest = &input[at..];
+        let mut incomplete = false;
+
+        for syntax in &self.syntaxes {
+            let name = syntax.name.as_bytes();
+            if !name.starts_with(&rest[..rest.len().min(name.len())]) {
+                continue;
+            }
+            match arguments(rest, name.len(), syntax) {
+                Lexed::Token(instruction, end) => return Lexed::Token(instruction, at + end),
+                Lexed::Incomplete => incomplete = true,
+                Lexed::Nothing => {}
+            }
+        }
+
+        match incomplete {
+            true => Lexed::Incomplete,
+            false => Lexed::Nothing,
+        }
+    }
+}
+
+fn instruction(name: &'static str, arguments: Vec<i64>) -> Instruction {
+    match (name, arguments.as_slice()) {
+        ("mul", [a, b]) => Instruction::Mul(*a, *b),
+        ("do", []) => Instruction::Do,
+        ("don't", []) => Instruction::Dont,
+        _ => Instruction::Call(name, arguments),
+    }
+}
+
+/// Reads the bracketed arguments of an instruction whose name ends at `at`.
+fn arguments(input: &[u8], mut at: usize, syntax: &Syntax) -> Lexed {
+    let mut arguments = Vec::new();
+
+    match input.get(at) {
+        Some(b'(') => at += 1,
+        Some(_) => return Lexed::Nothing,
+        None => return Lexed::Incomplete,
+    }
+
+    loop {
+        let digits = input[at..].iter().take(MAX_DIGITS + 1).take_while(|x| x.is_ascii_digit()).count();
+        let next = input.get(at + digits);
+
+        match (digits, next) {
+            (_, None) if digits <= MAX_DIGITS => return Lexed::Incomplete,
+            (0, Some(b')')) if arguments.is_empty() => {}
+            (1..=MAX_DIGITS, Some(b',' | b')')) => {
+                arguments.push(input[at..at + digits].iter().fold(0, |x, digit| x * 10 + i64::from(digit - b'0')));
+            }
+            _ => return Lexed::Nothing,
+        }
+        at += digits + 1;
+
+        if arguments.len() > syntax.max_arguments {
+            return Lexed::Nothing;
+        }
+        if next == Some(&b')') {
+            return match arguments.len() >= syntax.min_arguments {
+                true => Lexed::Token(instruction(syntax.name, arguments), at),
+                false => Lexed::Nothing,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_find_instructions_with_their_spans() {
+        // Given
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        // When
+        let tokens = Lexer::default().tokens(input).collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            vec![
+                Token { instruction: Instruction::Mul(2, 4), span: 1..9 },
+                Token { instruction: Instruction::Dont, span: 20..27 },
+                Token { instruction: Instruction::Mul(5, 5), span: 28..36 },
+                Token { instruction: Instruction::Mul(11, 8), span: 48..57 },
+                Token { instruction: Instruction::Do, span: 59..63 },
+                Token { instruction: Instruction::Mul(8, 5), span: 64..72 },
+            ],
+            tokens
+        );
+    }
+
+    #[test]
+    fn should_follow_registered_syntaxes() {
+        // Given
+        let lexer = Lexer::default()
+            .register(Syntax { name: "add", min_arguments: 1, max_arguments: 3 })
+            .register(Syntax::new("do", 1));
+        let input = "add(1,2,3)add()add(1,2,3,4)mul(1234,5)mul(1,2,)do()do(7)mul(4,5";
+
+        // When
+        let instructions = lexer.tokens(input).map(|x| x.instruction).collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(vec![Instruction::Call("add", vec![1, 2, 3]), Instruction::Call("do", vec![7])], instructions);
+        assert_eq!(Lexed::Incomplete, lexer.lex_at(input.as_bytes(), input.len() - 7));
+    }
+}
@@ -0,0 +1,68 @@
+mod interpreter;
+mod lexer;
+mod stream;
+
+pub use interpreter::{Call, Interpreter};
+pub use lexer::{Instruction, Lexed, Lexer, Syntax, Token};
+pub use stream::{Stream, Totals};
+
+/// Adds up every `mul` in the memory.
+pub fn part_1(input: &str) -> eyre::Result<i64> {
+    let lexer = Lexer::default();
+
+    Ok(Interpreter::ignoring_switches().run(lexer.tokens(input).map(|x| x.instruction)))
+}
+
+/// Adds up the `mul`s that `do()` and `don't()` leave enabled.
+pub fn part_2(input: &str) -> eyre::Result<i64> {
+    let lexer = Lexer::default();
+
+    Ok(Interpreter::default().run(lexer.tokens(input).map(|x| x.instruction)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_multiply_valid_statements() -> eyre::Result<()> {
+        // Given
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+
+        // When
+        let result = part_1(input)?;
+
+        // Then
+        assert_eq!(result, 161);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_multiply_valid_statements_after_do_switch() -> eyre::Result<()> {
+        // Given
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+        // When
+        let result = part_2(input)?;
+
+        // Then
+        assert_eq!(result, 48);
+
+        Ok(())
+    }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let crlf = input.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| part_1(&parsing::normalise(&x)).unwrap());
+
+        // Then
+        assert_eq!([161, 161], sums);
+    }
+}
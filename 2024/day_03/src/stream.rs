@@ -0,0 +1,129 @@
+use crate::interpreter::Interpreter;
+use crate::lexer::{Lexed, Lexer};
+use std::io;
+
+/// Both parts' totals for the memory read so far.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Totals {
+    pub part_1: i64,
+    pub part_2: i64,
+}
+
+/// Evaluates memory as it is read, a chunk at a time, giving the totals after each chunk. The
+/// bytes of an instruction cut off by the end of a chunk are kept until the next one finishes it.
+#[derive(Debug)]
+pub struct Stream<R> {
+    reader: R,
+    chunk: Vec<u8>,
+    pending: Vec<u8>,
+    lexer: Lexer,
+    part_1: Interpreter,
+    part_2: Interpreter,
+    finished: bool,
+}
+
+impl<R: io::Read> Stream<R> {
+    pub fn new(reader: R, chunk_size: usize) -> Stream<R> {
+        Stream {
+            reader,
+            chunk: vec![0; chunk_size.max(1)],
+            pending: Vec::new(),
+            lexer: Lexer::default(),
+            part_1: Interpreter::ignoring_switches(),
+            part_2: Interpreter::default(),
+            finished: false,
+        }
+    }
+
+    pub fn totals(&self) -> Totals {
+        Totals { part_1: self.part_1.total(), part_2: self.part_2.total() }
+    }
+
+    /// Runs the pending instructions, up to one that more input could still finish unless the
+    /// input has ended.
+    fn evaluate_pending(&mut self, ended: bool) {
+        let mut at = 0;
+
+        while at < self.pending.len() {
+            match self.lexer.lex_at(&self.pending, at) {
+                Lexed::Token(instruction, end) => {
+                    self.part_1.step(&instruction);
+                    self.part_2.step(&instruction);
+                    at = end;
+                }
+                Lexed::Incomplete if !ended => break,
+                Lexed::Nothing | Lexed::Incomplete => at += 1,
+            }
+        }
+
+        self.pending.drain(..at);
+    }
+}
+
+impl<R: io::Read> Iterator for Stream<R> {
+    type Item = io::Result<Totals>;
+
+    fn next(&mut self) -> Option<io::Result<Totals>> {
+        if self.finished {
+            return None;
+        }
+
+        let read = loop {
+            match self.reader.read(&mut self.chunk) {
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.finished = true;
+                    return Some(Err(error));
+                }
+                Ok(read) => break read,
+            }
+        };
+
+        self.finished = read == 0;
+        self.pending.extend_from_slice(&self.chunk[..read]);
+        self.evaluate_pending(self.finished);
+
+        Some(Ok(self.totals()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use io::Read;
+
+    const INPUT: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    fn last(stream: impl Iterator<Item = io::Result<Totals>>) -> Totals {
+        stream.map(Result::unwrap).last().unwrap()
+    }
+
+    #[test]
+    fn should_total_the_example_split_anywhere() {
+        // Given
+        let expected = Totals { part_1: 161, part_2: 48 };
+        let input = INPUT.as_bytes();
+
+        // When
+        let totals = (0..=input.len())
+            .map(|at| last(Stream::new(input[..at].chain(&input[at..]), input.len())))
+            .collect::<Vec<_>>();
+
+        // Then
+        assert!(totals.iter().all(|x| *x == expected), "{:?}", totals);
+    }
+
+    #[test]
+    fn should_total_the_example_a_byte_at_a_time() {
+        // Given
+        let stream = Stream::new(INPUT.as_bytes(), 1);
+
+        // When
+        let totals = stream.map(Result::unwrap).collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(INPUT.len() + 1, totals.len());
+        assert_eq!(Totals { part_1: 8, part_2: 8 }, totals["xmul(2,4)".len() - 1]);
+        assert_eq!(Totals { part_1: 161, part_2: 48 }, totals[INPUT.len()]);
+    }
+}
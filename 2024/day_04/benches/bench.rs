@@ -1,6 +1,5 @@
-use std::fs;
 use divan::AllocProfiler;
-use day_04::*;
+use y2024_day_04::*;
 
 fn main() {
     // Run registered benchmarks.
@@ -10,13 +9,13 @@ fn main() {
 #[divan::bench]
 fn part1() {
     part_1::solve(
-        divan::black_box(fs::read_to_string("./input.txt").unwrap())
+        &divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap())
     ).unwrap();
 }
 
 #[divan::bench]
 fn part2() {
     part_2::solve(
-        divan::black_box(fs::read_to_string("./input.txt").unwrap())
+        &divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap())
     ).unwrap();
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use y2024_day_04::part_2;
+fn main() -> eyre::Result<()> {
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
+
+    let result = part_2::solve(&input);
+
+    println!("Part 2: {}", result?);
+
+    Ok(())
+}
\ No newline at end of file
@@ -34,7 +34,7 @@ impl Direction {
     }
 }
 
-pub fn search(x: usize, y: usize, lines: &Vec<String>, direction: &Direction, depth: i32) -> String{
+pub fn search(x: usize, y: usize, lines: &[&str], direction: &Direction, depth: i32) -> String{
     let word = (0..depth)
         .enumerate()
         .filter_map(|(i, _)| {
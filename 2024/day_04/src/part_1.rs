@@ -1,8 +1,8 @@
 use eyre::ContextCompat;
 use crate::{search, DIRECTIONS};
 
-pub fn solve(input: String) -> eyre::Result<i32> {
-    let lines: Vec<String> = input.lines().map(|x| x.to_string()).collect::<Vec<_>>();
+pub fn solve(input: &str) -> eyre::Result<i32> {
+    let lines = input.lines().collect::<Vec<_>>();
 
     let mut count = 0;
 
@@ -42,11 +42,25 @@ MAMMMXMMMM
 MXMXAXMASX";
 
         // When
-        let result = solve(input.to_string())?;
+        let result = solve(input)?;
 
         // Then
         assert_eq!(result, 18);
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let input = "MMMSXXMASM\nMSAMXMSMSA\nAMXSXMAAMM\nMSAMASMSMX\nXMASAMXAMM\nXXAMMXXAMA\nSMSMSASXSS\nSAXAMASAAA\nMAMMMXMMMM\nMXMXAXMASX";
+        let crlf = input.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| solve(&parsing::normalise(&x)).unwrap());
+
+        // Then
+        assert_eq!([18, 18], sums);
+    }
+}
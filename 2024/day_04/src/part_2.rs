@@ -2,8 +2,8 @@ use eyre::ContextCompat;
 use crate::Direction::{NorthEast, NorthWest, SouthEast, SouthWest};
 use crate::search;
 
-pub fn solve(input: String) -> eyre::Result<i32> {
-    let lines: Vec<String> = input.lines().map(|x| x.to_string()).collect::<Vec<_>>();
+pub fn solve(input: &str) -> eyre::Result<i32> {
+    let lines = input.lines().collect::<Vec<_>>();
 
     let mut count = 0;
 
@@ -54,7 +54,7 @@ MAMMMXMMMM
 MXMXAXMASX";
 
         // When
-        let result = solve(input.to_string())?;
+        let result = solve(input)?;
 
         // Then
         assert_eq!(result, 9);
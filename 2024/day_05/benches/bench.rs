@@ -0,0 +1,19 @@
+use divan::AllocProfiler;
+use y2024_day_05::Puzzle;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    input.parse::<Puzzle>().unwrap().sum_of_correct_updates();
+}
+
+#[divan::bench]
+fn part2() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    input.parse::<Puzzle>().unwrap().sum_of_incorrect_updates();
+}
\ No newline at end of file
@@ -0,0 +1,12 @@
+use y2024_day_05::Puzzle;
+
+fn main() -> eyre::Result<()> {
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
+
+    let puzzle = input.parse::<Puzzle>()?;
+
+    println!("Part 1: {}", puzzle.sum_of_correct_updates());
+
+    Ok(())
+}
\ No newline at end of file
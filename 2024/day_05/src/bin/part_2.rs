@@ -0,0 +1,12 @@
+use y2024_day_05::Puzzle;
+
+fn main() -> eyre::Result<()> {
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
+
+    let result = input.parse::<Puzzle>()?.sum_of_incorrect_updates();
+
+    println!("Part 2: {}", result);
+
+    Ok(())
+}
\ No newline at end of file
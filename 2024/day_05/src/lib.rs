@@ -0,0 +1,193 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+use itertools::Itertools;
+use parsing::{ParseError, Segment, Separator};
+
+pub struct Puzzle {
+    rules: HashMap<usize,Vec<usize>>,
+    updates: Vec<Vec<usize>>,
+}
+
+impl FromStr for Puzzle {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Puzzle, ParseError> {
+        let (rules_vec, updates): (Vec<Segment>, Vec<Segment>) = Segment::new(input)
+            .lines()
+            .filter(|x| !x.trim().is_empty())
+            .partition(|x| x.text.contains('|'));
+
+        let mut rules = HashMap::<usize, Vec<usize>>::new();
+        for rule in rules_vec {
+            let (before, after) = rule.pair::<usize>("|")?;
+            rules.entry(before).or_default().push(after);
+        }
+
+        let updates = updates
+            .into_iter()
+            .map(|x| x.integers(Separator::Char(',')))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Puzzle { rules, updates })
+    }
+}
+
+impl Puzzle {
+    pub fn sum_of_correct_updates(&self) -> usize {
+        self.updates
+            .iter()
+            .filter(|x| self.is_valid(x))
+            .map(|x| Self::get_middle_page(x))
+            .sum()
+    }
+
+    pub fn sum_of_incorrect_updates(&self) -> usize {
+        self.updates
+            .iter()
+            .filter(|x| !self.is_valid(x))
+            .map(|x| self.correct_update(x.clone()))
+            .map(|x| Self::get_middle_page(&x))
+            .sum()
+    }
+
+    /// The rules `before|after` broken by `update`, in the order their pages appear.
+    pub fn violated_rules(&self, update: &[usize]) -> Vec<(usize, usize)> {
+        update
+            .iter()
+            .enumerate()
+            .flat_map(|(i, after)| update[..i].iter().map(move |before| (*after, *before)))
+            .filter(|(before, after)| self.rules.get(before).is_some_and(|x| x.contains(after)))
+            .collect()
+    }
+
+    fn get_middle_page(update: &Vec<usize>) -> usize {
+        let len = update.len();
+        let index = match len % 2 {
+            0 => len / 2 - 1,
+            _ => len / 2,
+        };
+
+        update[index]
+    }
+
+    fn is_valid(&self, update: &Vec<usize>) -> bool {
+        let empty = Vec::new();
+        let mut update_sorted = update.clone();
+        update_sorted.is_sorted_by(|a, b| self.rules.get(a).unwrap_or(&empty).iter().contains(&b))
+    }
+
+    pub fn correct_update(&self, mut update: Vec<usize>) -> Vec<usize> {
+        let empty = Vec::new();
+        let mut update_sorted = update.clone();
+        update_sorted.sort_by(|a, b| match self.rules.get(a).unwrap_or(&empty).iter().contains(&b) {
+            true => {Ordering::Greater}
+            false => {Ordering::Less}
+        });
+
+        update_sorted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parsing::{ParseErrorKind, Span};
+    const INPUT: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn should_solve_sum_of_correct_updates() {
+        // Given
+        let puzzle = Puzzle::from_str(INPUT).unwrap();
+
+        // When
+        let sum = puzzle.sum_of_correct_updates();
+
+        // Then
+        assert_eq!(143, sum);
+    }
+
+    #[test]
+    fn should_solve_sum_of_incorrect_updates() {
+        // Given
+        let puzzle = Puzzle::from_str(INPUT).unwrap();
+
+        // When
+        let sum = puzzle.sum_of_incorrect_updates();
+
+        // Then
+        assert_eq!(123, sum);
+    }
+
+    #[test]
+    fn should_find_violated_rules() {
+        // Given
+        let puzzle = Puzzle::from_str(INPUT).unwrap();
+
+        // When
+        let violations = [vec![75, 47, 61, 53, 29], vec![97, 13, 75, 29, 47]].map(|x| puzzle.violated_rules(&x));
+
+        // Then
+        assert_eq!([vec![], vec![(75, 13), (29, 13), (47, 13), (47, 29)]], violations);
+    }
+
+    #[test]
+    fn should_reject_malformed_rules_and_updates() {
+        // Given
+        let inputs = ["47|\n\n75,47", "|53\n\n75,47", "1|2|3\n\n75,47", "47|53\n\n75,x,61"];
+
+        // When
+        let errors = inputs.map(|x| Puzzle::from_str(x).err().map(|x| (x.kind, x.span)));
+
+        // Then
+        assert_eq!(
+            [
+                Some((ParseErrorKind::Empty, Span::new(3, 3))),
+                Some((ParseErrorKind::Empty, Span::new(0, 0))),
+                Some((ParseErrorKind::InvalidInteger, Span::new(2, 5))),
+                Some((ParseErrorKind::InvalidInteger, Span::new(10, 11))),
+            ],
+            errors
+        );
+    }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::from_str(&parsing::normalise(&x)).unwrap().sum_of_correct_updates());
+
+        // Then
+        assert_eq!([143, 143], sums);
+    }
+}
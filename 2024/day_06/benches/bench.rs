@@ -0,0 +1,20 @@
+use y2024_day_06::{LoopSearch, Puzzle, LOOP_SEARCHES};
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR"))).unwrap();
+    let puzzle = Puzzle::try_from(&*input).unwrap();
+    puzzle.distinct_positions_visited();
+}
+
+#[divan::bench(args = LOOP_SEARCHES)]
+fn part2(search: LoopSearch) {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    let puzzle = Puzzle::try_from(&*input).unwrap();
+    puzzle.potential_loop_opportunities_with(search);
+}
@@ -0,0 +1,12 @@
+use y2024_day_06::Puzzle;
+
+fn main() -> eyre::Result<()> {
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
+
+    let puzzle = Puzzle::try_from(&*input).unwrap();
+
+    println!("Part 1: {}", puzzle.distinct_positions_visited());
+
+    Ok(())
+}
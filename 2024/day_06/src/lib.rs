@@ -0,0 +1,447 @@
+use crate::Direction::{East, North, South, West};
+use itertools::Itertools;
+use bitset::{PointSet, VisitedMap};
+use cancel::{CancellationToken, Cancelled};
+use rayon::prelude::*;
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+pub const DIRECTIONS: [Direction; 4] = [North, East, South, West];
+
+impl Direction {
+    pub fn traversal(&self) -> (isize, isize) {
+        match self {
+            North => (0, -1),
+            East => (1, 0),
+            South => (0, 1),
+            West => (-1, 0),
+        }
+    }
+
+    /// The direction's position in [`DIRECTIONS`], for indexing a [`VisitedMap`].
+    pub fn index(&self) -> usize {
+        self.clone() as usize
+    }
+
+    pub fn rotate(&self) -> Self {
+        match self {
+            North => East,
+            East => South,
+            South => West,
+            West => North,
+        }
+    }
+}
+
+#[derive(Debug, Eq, Hash, Clone)]
+struct Point(usize, usize);
+
+#[derive(Debug, Clone)]
+struct Guard {
+    point: Point,
+    direction: Direction,
+}
+
+pub enum WalkResult {
+    WalkedTo(Point),
+    ObstructedAt(Point),
+}
+
+impl Guard {
+    fn new(point: Point, direction: Direction) -> Self {
+        Guard { point, direction }
+    }
+
+    fn try_walk(&mut self, map: &Map) -> WalkResult {
+        let new_point = self.point.gen_point_for_direction(&self.direction).unwrap();
+
+        match map.point_is_obstructed(&new_point) {
+            true => WalkResult::ObstructedAt(new_point),
+            false => {
+                self.point = new_point.clone();
+                WalkResult::WalkedTo(new_point)
+            }
+        }
+    }
+
+    fn turn(&mut self) {
+        self.direction = self.direction.rotate();
+    }
+}
+
+/// The rows of the map, borrowed from the input until a row is changed.
+#[derive(Debug, Clone)]
+struct Map<'a> {
+    points: Vec<Cow<'a, [u8]>>,
+}
+
+impl Map<'_> {
+    fn with_obstruction_at(&self, point: &Point) -> Self {
+        let mut new = self.clone();
+        new.points[point.1].to_mut()[point.0] = b'#';
+
+        new
+    }
+
+    fn point_is_obstructed(&self, point: &Point) -> bool {
+        self.points[point.1][point.0] == b'#'
+    }
+
+    fn width(&self) -> usize {
+        self.points.first().map_or(0, |x| x.len())
+    }
+
+    fn height(&self) -> usize {
+        self.points.len()
+    }
+
+    fn obstructions_encountered(&self) -> VisitedMap {
+        VisitedMap::new(self.width(), self.height(), DIRECTIONS.len())
+    }
+
+    fn at_map_boundary(&self, point: &Point) -> bool {
+        let vertical_max = self.points.len() - 1;
+        let horizontal_max = self.points.first().map(|x| x.len() - 1).unwrap_or(0);
+
+        point.0 < 1 || point.0 >= horizontal_max || point.1 < 1 || point.1 >= vertical_max
+    }
+}
+
+impl Point {
+    fn gen_point_for_direction(&self, direction: &Direction) -> Option<Point> {
+        let traversal = direction.traversal();
+        let x: usize = (self.0 as i32 + (traversal.0 as i32)).try_into().ok()?;
+        let y: usize = (self.1 as i32 + (traversal.1 as i32)).try_into().ok()?;
+
+        Some(Point(x, y))
+    }
+}
+
+impl PartialEq<Self> for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+#[derive(Debug)]
+pub struct Puzzle<'a> {
+    map: Map<'a>,
+    guard: Guard,
+}
+
+pub enum PuzzleParsingError {
+    CouldNotFindGuard,
+}
+
+impl<'a> TryFrom<&'a str> for Puzzle<'a> {
+    type Error = ();
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let guard = input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter_map(|(x, c)| match c {
+                        '.' => None,
+                        '^' => Some(Guard::new(Point(x, y), North)),
+                        '>' => Some(Guard::new(Point(x, y), East)),
+                        'v' => Some(Guard::new(Point(x, y), South)),
+                        '<' => Some(Guard::new(Point(x, y), West)),
+                        _ => None,
+                    })
+                    .collect::<Vec<Guard>>()
+            })
+            .flatten()
+            .next()
+            .unwrap();
+
+        let map = Map {
+            points: input.lines().map(|x| Cow::Borrowed(x.as_bytes())).collect(),
+        };
+
+        Ok(Puzzle { guard, map })
+    }
+}
+
+impl Puzzle<'_> {
+    /// Copies the map's rows so the puzzle no longer borrows its input.
+    pub fn into_owned(self) -> Puzzle<'static> {
+        let points = self.map.points.into_iter().map(|x| Cow::Owned(x.into_owned())).collect();
+
+        Puzzle {
+            map: Map { points },
+            guard: self.guard,
+        }
+    }
+}
+
+/// The ways of finding loop opportunities, kept side by side to compare them.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum LoopSearch {
+    /// Walks a copy of the map with each obstruction added, from the guard's starting point.
+    CloneMap,
+    /// Resumes from where the guard stood just before each obstruction, checking it on the fly.
+    Resume,
+}
+
+pub const LOOP_SEARCHES: [LoopSearch; 2] = [LoopSearch::CloneMap, LoopSearch::Resume];
+
+enum NavigateMapResult {
+    ReachedExit(PointSet),
+    EncounteredLoop,
+}
+
+/// Where the guard's patrol ends up.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Patrol {
+    /// Leaves the map having visited this many distinct positions.
+    Exits(usize),
+    Loops,
+}
+
+impl Puzzle<'_> {
+    pub fn distinct_positions_visited(&self) -> usize {
+        self.try_distinct_positions_visited(&CancellationToken::new())
+            .expect("An uncancelled walk should finish")
+    }
+
+    pub fn try_distinct_positions_visited(&self, cancel: &CancellationToken) -> Result<usize, Cancelled> {
+        Ok(match Self::navigate_map(&self.map, &mut self.guard.clone(), cancel)? {
+            NavigateMapResult::ReachedExit(points) => points.len(),
+            NavigateMapResult::EncounteredLoop => 0,
+        })
+    }
+
+    fn navigate_map(map: &Map, guard: &mut Guard, cancel: &CancellationToken) -> Result<NavigateMapResult, Cancelled> {
+        let mut points_visited = PointSet::new(map.width(), map.height());
+        let mut obstructions_encountered = map.obstructions_encountered();
+        points_visited.insert(guard.point.0, guard.point.1);
+        loop {
+            cancel.check()?;
+            match guard.try_walk(&map) {
+                WalkResult::WalkedTo(point) => {
+                    points_visited.insert(point.0, point.1);
+
+                    if map.at_map_boundary(&point) {
+                        break;
+                    }
+                }
+                WalkResult::ObstructedAt(point) => {
+                    if !obstructions_encountered.insert(point.0, point.1, guard.direction.index()) {
+                        return Ok(NavigateMapResult::EncounteredLoop);
+                    }
+
+                    guard.turn();
+                }
+            }
+        }
+
+        Ok(NavigateMapResult::ReachedExit(points_visited))
+    }
+
+    pub fn potential_loop_opportunities(&self) -> usize {
+        self.potential_loop_opportunities_with(LoopSearch::Resume)
+    }
+
+    pub fn potential_loop_opportunities_with(&self, search: LoopSearch) -> usize {
+        self.try_potential_loop_opportunities_with(search, &CancellationToken::new())
+            .expect("An uncancelled search should finish")
+    }
+
+    /// Like `potential_loop_opportunities_with`, but gives up once `cancel` is cancelled.
+    pub fn try_potential_loop_opportunities_with(
+        &self,
+        search: LoopSearch,
+        cancel: &CancellationToken,
+    ) -> Result<usize, Cancelled> {
+        match search {
+            LoopSearch::CloneMap => self.loops_by_cloning_map(cancel),
+            LoopSearch::Resume => self.loops_by_resuming(cancel),
+        }
+    }
+
+    fn loops_by_cloning_map(&self, cancel: &CancellationToken) -> Result<usize, Cancelled> {
+        let positions_visited = match Self::navigate_map(&self.map, &mut self.guard.clone(), cancel)? {
+            NavigateMapResult::ReachedExit(points) => points.iter().map(|(x, y)| Point(x, y)).collect(),
+            NavigateMapResult::EncounteredLoop => Vec::new(),
+        };
+
+        positions_visited
+            .par_iter()
+            .map(|x| {
+                let map = self.map.with_obstruction_at(x);
+                let mut guard = self.guard.clone();
+
+                Ok(match Self::navigate_map(&map, &mut guard, cancel)? {
+                    NavigateMapResult::ReachedExit(_) => 0,
+                    NavigateMapResult::EncounteredLoop => 1,
+                })
+            })
+            .try_reduce(|| 0, |a, b| Ok(a + b))
+    }
+
+    /// Every point on the guard's route, with the guard as it was just before first reaching
+    /// it. The starting point is paired with the guard as it starts.
+    fn route_with_approaches(&self) -> Vec<(Point, Guard)> {
+        let mut guard = self.guard.clone();
+        let mut visited = PointSet::new(self.map.width(), self.map.height());
+        visited.insert(guard.point.0, guard.point.1);
+        let mut route = vec![(guard.point.clone(), guard.clone())];
+        let mut obstructions_encountered = self.map.obstructions_encountered();
+
+        loop {
+            let approach = guard.clone();
+            match guard.try_walk(&self.map) {
+                WalkResult::WalkedTo(point) => {
+                    if visited.insert(point.0, point.1) {
+                        route.push((point.clone(), approach));
+                    }
+                    if self.map.at_map_boundary(&point) {
+                        return route;
+                    }
+                }
+                WalkResult::ObstructedAt(point) => {
+                    if !obstructions_encountered.insert(point.0, point.1, guard.direction.index()) {
+                        return Vec::new();
+                    }
+                    guard.turn();
+                }
+            }
+        }
+    }
+
+    fn loops_with_obstruction(map: &Map, mut guard: Guard, obstruction: &Point) -> bool {
+        let mut obstructions_encountered = map.obstructions_encountered();
+
+        loop {
+            let next = guard.point.gen_point_for_direction(&guard.direction).unwrap();
+            if map.point_is_obstructed(&next) || next == *obstruction {
+                if !obstructions_encountered.insert(next.0, next.1, guard.direction.index()) {
+                    return true;
+                }
+                guard.turn();
+            } else if map.at_map_boundary(&next) {
+                return false;
+            } else {
+                guard.point = next;
+            }
+        }
+    }
+
+    fn loops_by_resuming(&self, cancel: &CancellationToken) -> Result<usize, Cancelled> {
+        self.route_with_approaches()
+            .into_par_iter()
+            .map(|(point, approach)| {
+                cancel.check()?;
+                Ok(usize::from(Self::loops_with_obstruction(&self.map, approach, &point)))
+            })
+            .try_reduce(|| 0, |a, b| Ok(a + b))
+    }
+
+    /// Walks the guard's patrol, with an extra obstruction at `(x, y)` if given. Gives `None`
+    /// for an obstruction off the map or where the guard starts.
+    pub fn patrol(&self, obstruction: Option<(usize, usize)>) -> Option<Patrol> {
+        let map = match obstruction {
+            Some((x, y)) if x >= self.map.width() || y >= self.map.height() => return None,
+            Some((x, y)) if self.guard.point == Point(x, y) => return None,
+            Some((x, y)) => self.map.with_obstruction_at(&Point(x, y)),
+            None => self.map.clone(),
+        };
+
+        let result = Self::navigate_map(&map, &mut self.guard.clone(), &CancellationToken::new())
+            .expect("An uncancelled walk should finish");
+        Some(match result {
+            NavigateMapResult::ReachedExit(points) => Patrol::Exits(points.len()),
+            NavigateMapResult::EncounteredLoop => Patrol::Loops,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn should_solve_part_1() {
+        // Given
+        let puzzle = Puzzle::try_from(INPUT).unwrap();
+
+        // When
+        let sum = puzzle.distinct_positions_visited();
+
+        // Then
+        assert_eq!(41, sum);
+    }
+
+    #[test]
+    fn should_solve_part_2() {
+        // Given
+        let puzzle = Puzzle::try_from(INPUT).unwrap();
+
+        // When
+        let sums = LOOP_SEARCHES.map(|x| puzzle.potential_loop_opportunities_with(x));
+
+        // Then
+        assert_eq!([6, 6], sums);
+    }
+
+    #[test]
+    fn should_stop_once_cancelled() {
+        // Given
+        let puzzle = Puzzle::try_from(INPUT).unwrap();
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+
+        // When
+        let visited = puzzle.try_distinct_positions_visited(&cancel);
+        let loops = LOOP_SEARCHES.map(|x| puzzle.try_potential_loop_opportunities_with(x, &cancel));
+
+        // Then
+        assert_eq!(Err(Cancelled), visited);
+        assert_eq!([Err(Cancelled), Err(Cancelled)], loops);
+    }
+
+    #[test]
+    fn should_patrol_around_an_extra_obstruction() {
+        // Given
+        let puzzle = Puzzle::try_from(INPUT).unwrap().into_owned();
+
+        // When
+        let patrols = [None, Some((3, 6)), Some((0, 0)), Some((4, 6)), Some((10, 0))].map(|x| puzzle.patrol(x));
+
+        // Then
+        assert_eq!([Some(Patrol::Exits(41)), Some(Patrol::Loops), Some(Patrol::Exits(41)), None, None], patrols);
+    }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::try_from(&*parsing::normalise(&x)).unwrap().distinct_positions_visited());
+
+        // Then
+        assert_eq!([41, 41], sums);
+    }
+}
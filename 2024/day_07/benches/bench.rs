@@ -0,0 +1,21 @@
+use y2024_day_07::Puzzle;
+use std::str::FromStr;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR"))).unwrap();
+    let puzzle = Puzzle::from_str(&input).unwrap();
+    puzzle.part_1();
+}
+
+#[divan::bench]
+fn part2() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    let puzzle = Puzzle::from_str(&input).unwrap();
+    puzzle.part_2();
+}
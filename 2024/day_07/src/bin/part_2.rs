@@ -1,9 +1,9 @@
-use day_10::Puzzle;
-use std::fs;
+use y2024_day_07::Puzzle;
 use std::str::FromStr;
 
 fn main() -> eyre::Result<()> {
-    let input = fs::read_to_string("./input.txt")?;
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
 
     let puzzle = Puzzle::from_str(&input).unwrap();
 
@@ -1,4 +1,5 @@
 use crate::Operator::{Divide, IsConcatenable, Subtract};
+use parsing::{ParseError, Segment, Separator};
 use rayon::prelude::*;
 use std::iter::once;
 use std::str::FromStr;
@@ -34,12 +35,10 @@ struct ReversedEquation {
 }
 
 impl ReversedEquation {
-    fn new(all_inputs: Vec<i64>) -> Option<ReversedEquation> {
-        let mut iter = all_inputs.into_iter();
-        let result = iter.next()?;
-        let inputs = iter.chain(once(result)).rev().collect::<Vec<_>>();
+    fn new(result: i64, inputs: Vec<i64>) -> ReversedEquation {
+        let inputs = inputs.into_iter().chain(once(result)).rev().collect::<Vec<_>>();
 
-        Some(ReversedEquation { inputs })
+        ReversedEquation { inputs }
     }
 
     fn has_successful_variation(&self, applicable_operators: &Vec<Operator>) -> bool {
@@ -85,18 +84,16 @@ pub struct Puzzle {
 }
 
 impl FromStr for Puzzle {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let equations = input
+        let equations = Segment::new(input)
             .lines()
-            .map(|x| {
-                x.split([':', ' '])
-                    .filter_map(|x| x.parse::<i64>().ok())
-                    .collect()
+            .map(|line| {
+                let (result, inputs) = line.key_values(":", Separator::Whitespace)?;
+                Ok(ReversedEquation::new(result, inputs))
             })
-            .filter_map(|x| ReversedEquation::new(x))
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, ParseError>>()?;
 
         Ok(Puzzle { equations })
     }
@@ -156,4 +153,17 @@ mod tests {
         // Then
         assert_eq!(11387, sum);
     }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::from_str(&parsing::normalise(&x)).unwrap().part_1());
+
+        // Then
+        assert_eq!([3749, 3749], sums);
+    }
 }
@@ -0,0 +1,22 @@
+use std::str::FromStr;
+use divan::AllocProfiler;
+use y2024_day_08::Puzzle;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR"))).unwrap();
+    let puzzle = Puzzle::from_str(&input).unwrap();
+    puzzle.part_1();
+}
+
+#[divan::bench]
+fn part2() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    let puzzle = Puzzle::from_str(&input).unwrap();
+    puzzle.part_2();
+}
\ No newline at end of file
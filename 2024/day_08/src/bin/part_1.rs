@@ -1,9 +1,9 @@
-use std::fs;
 use std::str::FromStr;
-use day_08::Puzzle;
+use y2024_day_08::Puzzle;
 
 fn main() -> eyre::Result<()> {
-    let input = fs::read_to_string("./input.txt")?;
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
 
     let puzzle = Puzzle::from_str(&input).unwrap();
 
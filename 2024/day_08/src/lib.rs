@@ -1,3 +1,4 @@
+use bitset::PointSet;
 use itertools::Itertools;
 use rayon::prelude::*;
 use std::collections::HashMap;
@@ -28,6 +29,11 @@ impl PointGenerator {
         }
     }
 
+    /// An empty set covering every point the generator can produce.
+    fn point_set(&self) -> PointSet {
+        PointSet::new(self.maximum_x + 1, self.maximum_y + 1)
+    }
+
     fn resonant_antinodes(&self, a: &Point, b: &Point) -> Vec<Point> {
         match self.antinode(a, b).is_some() {
             true => {
@@ -114,8 +120,8 @@ impl FromStr for Puzzle {
 
 impl Puzzle {
     pub fn part_1(&self) -> usize {
-        let antinodes = self
-            .node_map
+        let mut antinodes = self.point_generator.point_set();
+        self.node_map
             .iter()
             .map(|(_, nodes)| {
                 nodes
@@ -130,15 +136,16 @@ impl Puzzle {
                     .collect::<Vec<Point>>()
             })
             .flatten()
-            .unique()
-            .collect::<Vec<Point>>();
+            .for_each(|x| {
+                antinodes.insert(x.x, x.y);
+            });
 
-        antinodes.iter().count()
+        antinodes.len()
     }
 
     pub fn part_2(&self) -> usize {
-        let resonant_antinodes = self
-            .node_map
+        let mut resonant_antinodes = self.point_generator.point_set();
+        self.node_map
             .iter()
             .map(|(_, nodes)| {
                 nodes
@@ -154,10 +161,11 @@ impl Puzzle {
                     .collect::<Vec<Point>>()
             })
             .flatten()
-            .unique()
-            .collect::<Vec<Point>>();
+            .for_each(|x| {
+                resonant_antinodes.insert(x.x, x.y);
+            });
 
-        resonant_antinodes.iter().count()
+        resonant_antinodes.len()
     }
 }
 
@@ -202,4 +210,17 @@ mod tests {
         // Then
         assert_eq!(34, sum);
     }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::from_str(&parsing::normalise(&x)).unwrap().part_1());
+
+        // Then
+        assert_eq!([14, 14], sums);
+    }
 }
@@ -128,20 +128,44 @@ impl FromStr for Puzzle {
 impl Puzzle {
     pub fn part_1(&self) -> u64 {
         let fragmented_disk_space = Self::fragment(&self.disk_space);
-        let ordered_fragments = self.order_fragments(fragmented_disk_space);
+        let ordered_fragments = self.order_fragments(fragmented_disk_space, usize::MAX);
 
         Self::checksum(&ordered_fragments)
     }
 
     pub fn part_2(&self) -> u64 {
-        let ordered_disk_items = self.order_preserved();
+        let ordered_disk_items = self.order_preserved(usize::MAX);
 
         let fragments = Self::fragment(&ordered_disk_items);
 
         Self::checksum(&fragments)
     }
 
-    fn order_fragments(&self, fragments: Vec<DiskFragment>) -> Vec<DiskFragment> {
+    /// The disk after part 1 has moved `moves` blocks, one character per block.
+    pub fn layout_after_block_moves(&self, moves: usize) -> String {
+        let fragments = self.order_fragments(Self::fragment(&self.disk_space), moves);
+
+        Self::render(&fragments)
+    }
+
+    /// The disk after part 2 has tried to move `moves` files, one character per block.
+    pub fn layout_after_file_moves(&self, moves: usize) -> String {
+        Self::render(&Self::fragment(&self.order_preserved(moves)))
+    }
+
+    /// Shows free blocks as `.` and file ids above 9 in brackets, like `00...[10][10]`.
+    fn render(fragments: &[DiskFragment]) -> String {
+        fragments
+            .iter()
+            .map(|x| match x {
+                FileFragment(id) if id.value < 10 => id.value.to_string(),
+                FileFragment(id) => format!("[{}]", id.value),
+                SpaceFragment => ".".to_string(),
+            })
+            .collect()
+    }
+
+    fn order_fragments(&self, fragments: Vec<DiskFragment>, moves: usize) -> Vec<DiskFragment> {
         let mut ordered_fragments = fragments.clone();
         let (files, space_indexes): (Vec<(usize, FileId)>, Vec<(usize)>) = fragments
             .into_iter()
@@ -156,6 +180,7 @@ impl Puzzle {
         _ = files
             .into_iter()
             .rev()
+            .take(moves)
             .try_for_each(|(file_index, file_id)| {
                 if let Some(space_index) = space_indexes_iter.next() {
                     return match space_index < file_index {
@@ -173,11 +198,11 @@ impl Puzzle {
         ordered_fragments
     }
 
-    fn order_preserved(&self) -> Vec<DiskSpace> {
+    fn order_preserved(&self, moves: usize) -> Vec<DiskSpace> {
         let (mut files, mut spaces): (Vec<(usize, DiskSpace)>, Vec<(usize, DiskSpace)>) = self
             .partition_disk_space();
 
-        files.iter_mut().rev().for_each(|(file_index, file_space)| {
+        files.iter_mut().rev().take(moves).for_each(|(file_index, file_space)| {
             let file = { file_space.files.iter_mut().next().unwrap().clone() };
             if let Some(target_space) = spaces
                 .iter_mut()
@@ -272,4 +297,32 @@ mod tests {
         // Then
         assert_eq!(2858, sum);
     }
+
+    #[test]
+    fn should_lay_out_the_disk_part_way_through() {
+        // Given
+        let puzzle = Puzzle::from_str(INPUT).unwrap();
+
+        // When
+        let blocks = [0, 2].map(|x| puzzle.layout_after_block_moves(x));
+        let files = puzzle.layout_after_file_moves(3);
+
+        // Then
+        assert_eq!("00...111...2...333.44.5555.6666.777.888899", blocks[0]);
+        assert_eq!("0099.111...2...333.44.5555.6666.777.8888..", blocks[1]);
+        assert_eq!("0099.1117772...333.44.5555.6666.....8888..", files);
+    }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::from_str(&parsing::normalise(&x)).unwrap().part_1());
+
+        // Then
+        assert_eq!([1928, 1928], sums);
+    }
 }
@@ -103,4 +103,17 @@ mod tests {
         // Then
         assert_eq!(81, sum);
     }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::from_str(&parsing::normalise(&x)).unwrap().part_1());
+
+        // Then
+        assert_eq!([36, 36], sums);
+    }
 }
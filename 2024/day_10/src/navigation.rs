@@ -1,5 +1,5 @@
 use crate::direction::{Direction, DIRECTIONS};
-use itertools::Itertools;
+use bitset::PointSet;
 
 pub type Map = Vec<Vec<u32>>;
 
@@ -20,7 +20,10 @@ impl Navigate for Map {
                 .collect()
         });
 
-        final_positions.iter().unique().count() as u32
+        let mut trail_ends = PointSet::new(self.first().map_or(0, |x| x.len()), self.len());
+        trail_ends.extend(final_positions.iter().map(|x| (x.point.x, x.point.y)));
+
+        trail_ends.len() as u32
     }
 
     fn get_trailhead_rating(&self, position: MapPosition) -> u32 {
@@ -1,11 +1,13 @@
+use cancel::{CancellationToken, Cancelled};
+use parsing::{ParseError, Segment, Separator};
 use std::collections::HashMap;
 use std::str::FromStr;
 
 impl FromStr for Puzzle {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let stones = input.split(' ').filter_map(|x| x.parse().ok()).collect();
+        let stones = Segment::new(input).integers(Separator::Whitespace)?;
 
         Ok(Puzzle { stones })
     }
@@ -17,21 +19,30 @@ pub struct Puzzle {
 
 impl Puzzle {
     pub fn part_1(&self) -> u64 {
-        self.count_for_blinks(25)
+        self.try_part_1(&CancellationToken::new()).expect("Uncancelled blinks should finish")
     }
 
     pub fn part_2(&self) -> u64 {
-        self.count_for_blinks(75)
+        self.try_part_2(&CancellationToken::new()).expect("Uncancelled blinks should finish")
     }
 
-    fn count_for_blinks(&self, depth: u8) -> u64 {
+    pub fn try_part_1(&self, cancel: &CancellationToken) -> Result<u64, Cancelled> {
+        self.count_for_blinks(25, cancel)
+    }
+
+    pub fn try_part_2(&self, cancel: &CancellationToken) -> Result<u64, Cancelled> {
+        self.count_for_blinks(75, cancel)
+    }
+
+    fn count_for_blinks(&self, depth: u8, cancel: &CancellationToken) -> Result<u64, Cancelled> {
         let mut cache: HashMap<u64, u64> = HashMap::new();
         self.stones
             .clone()
             .into_iter()
             .for_each(|x| {*cache.entry(x).or_insert(0) += 1});
 
-        (0..depth).for_each(|current_depth| {
+        for _ in 0..depth {
+            cancel.check()?;
             let mut cache_iteration : HashMap<u64, u64>= HashMap::new();
 
             cache
@@ -41,9 +52,9 @@ impl Puzzle {
                 });
 
             cache = cache_iteration;
-        });
+        }
 
-        cache.values().sum()
+        Ok(cache.values().sum())
     }
 
     fn blink(cache_iteration: &mut HashMap<u64, u64>, stone: &u64, count: &u64) {
@@ -91,4 +102,33 @@ mod tests {
         // Then
         assert_eq!(65601038650482, sum);
     }
+
+    #[test]
+    fn should_stop_blinking_once_cancelled() {
+        // Given
+        let puzzle = Puzzle::from_str(INPUT).unwrap();
+        let cancel = CancellationToken::new();
+
+        // When
+        let before = puzzle.try_part_1(&cancel);
+        cancel.cancel();
+        let after = puzzle.try_part_2(&cancel);
+
+        // Then
+        assert_eq!(Ok(55312), before);
+        assert_eq!(Err(Cancelled), after);
+    }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::from_str(&parsing::normalise(&x)).unwrap().part_1());
+
+        // Then
+        assert_eq!([55312, 55312], sums);
+    }
 }
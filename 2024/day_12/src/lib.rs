@@ -0,0 +1,210 @@
+mod direction;
+mod region;
+
+use crate::direction::STRAIGHT;
+use crate::region::Area;
+use bitset::PointSet;
+use direction::Point;
+use parsing::{ParseError, Segment};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use rayon::prelude::*;
+
+pub struct Puzzle {
+    /// Each region's plant, and its points relative to the corner of the smallest rectangle
+    /// around them, so that a region's set is sized to it rather than to the whole map.
+    regions: Vec<(char, Point, PointSet)>,
+}
+
+/// A region's plant and measurements.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RegionSummary {
+    pub plant: char,
+    pub area: u32,
+    pub perimeter: u32,
+    pub sides: u32,
+}
+
+impl FromStr for Puzzle {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut regions = Arc::new(Mutex::new(Vec::new()));
+
+        let plants = Segment::new(input).grid(Some)?;
+        let (width, height) = (plants.width(), plants.height());
+        let mut grid: HashMap<char, PointSet> = HashMap::new();
+
+        plants.cells().for_each(|((x, y), c)| {
+            grid.entry(*c).or_insert_with(|| PointSet::new(width, height)).insert(x, y);
+        });
+
+        fn build_region_points(
+            points: &mut PointSet,
+            point: Point,
+            contacting_points: &mut Vec<Point>,
+        ) {
+            if points.remove(point.x, point.y) {
+                STRAIGHT
+                    .iter()
+                    .filter_map(|direction| point.gen_in_direction(direction))
+                    .for_each(|point| {
+                        if points.contains(point.x, point.y) {
+                                build_region_points(points, point, contacting_points)
+                        }
+                    });
+
+                contacting_points.push(point);
+            }
+        }
+
+        grid.into_par_iter().for_each(|(char, mut points)| {
+            points.clone().iter().for_each(|(x, y)| {
+                if points.contains(x, y) {
+                    let mut contacting_points = Vec::new();
+                    build_region_points(&mut points, Point { x, y }, &mut contacting_points);
+                    if !contacting_points.is_empty() {
+                        let (origin, region) = to_region(&contacting_points);
+                        let regions = regions.clone();
+                        if let Some(mut regions) = regions.lock().ok() {
+                            regions.push((char, origin, region));
+                        };
+                    }
+                }
+            })
+        });
+
+        Ok(Puzzle { regions: Arc::try_unwrap(regions).unwrap().into_inner().unwrap() })
+    }
+}
+
+/// The corner of the smallest rectangle around the points, and the points relative to it.
+fn to_region(points: &[Point]) -> (Point, PointSet) {
+    let origin = Point {
+        x: points.iter().map(|point| point.x).min().unwrap_or(0),
+        y: points.iter().map(|point| point.y).min().unwrap_or(0),
+    };
+    let width = points.iter().map(|point| point.x - origin.x + 1).max().unwrap_or(0);
+    let height = points.iter().map(|point| point.y - origin.y + 1).max().unwrap_or(0);
+
+    let mut region = PointSet::new(width, height);
+    region.extend(points.iter().map(|point| (point.x - origin.x, point.y - origin.y)));
+
+    (origin, region)
+}
+
+impl Puzzle {
+    pub fn part_1(&self) -> u32 {
+        self.regions
+            .par_iter()
+            .map(|(_, _, region)| region.area() * region.perimeter())
+            .sum()
+    }
+
+    pub fn part_2(&self) -> u32 {
+        self.regions
+            .par_iter()
+            .map(|(_, _, region)| region.area() * region.sides())
+            .sum()
+    }
+
+    /// The region containing `(x, y)`, or `None` if the point is off the map.
+    pub fn region_at(&self, x: usize, y: usize) -> Option<RegionSummary> {
+        self.regions
+            .iter()
+            .find(|(_, origin, region)| {
+                x.checked_sub(origin.x)
+                    .zip(y.checked_sub(origin.y))
+                    .is_some_and(|(x, y)| region.contains(x, y))
+            })
+            .map(|(plant, _, region)| RegionSummary {
+                plant: *plant,
+                area: region.area(),
+                perimeter: region.perimeter(),
+                sides: region.sides(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parsing::{ParseErrorKind, Span};
+    const INPUT: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+    #[test]
+    fn should_solve_part_1() {
+        // Given
+        let puzzle = Puzzle::from_str(INPUT).unwrap();
+
+        // When
+        let sum = puzzle.part_1();
+
+        // Then
+        assert_eq!(1930, sum);
+    }
+
+    #[test]
+    fn should_solve_part_2() {
+        // Given
+        let puzzle = Puzzle::from_str(INPUT).unwrap();
+
+        // When
+        let sum = puzzle.part_2();
+
+        // Then
+        assert_eq!(1206, sum);
+    }
+
+    #[test]
+    fn should_measure_the_region_at_a_point() {
+        // Given
+        let puzzle = Puzzle::from_str(INPUT).unwrap();
+
+        // When
+        let regions = [(0, 0), (9, 0), (10, 0)].map(|(x, y)| puzzle.region_at(x, y));
+
+        // Then
+        let r = RegionSummary { plant: 'R', area: 12, perimeter: 18, sides: 10 };
+        let f = RegionSummary { plant: 'F', area: 10, perimeter: 18, sides: 12 };
+        assert_eq!([Some(r), Some(f), None], regions);
+    }
+
+    #[test]
+    fn should_reject_ragged_rows() {
+        // Given
+        let input = "AAB\nABBB\nCCC";
+
+        // When
+        let error = Puzzle::from_str(input).err();
+
+        // Then
+        assert_eq!(
+            Some((ParseErrorKind::RaggedGrid { expected: 3, found: 4 }, Span::new(4, 8))),
+            error.map(|x| (x.kind, x.span))
+        );
+    }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::from_str(&parsing::normalise(&x)).unwrap().part_1());
+
+        // Then
+        assert_eq!([1930, 1930], sums);
+    }
+}
@@ -2,7 +2,7 @@ use crate::direction::Direction::{
     East, North, NorthEast, NorthWest, South, SouthEast, SouthWest, West,
 };
 use crate::direction::{Direction, Point, DIAGONAL, STRAIGHT};
-use std::collections::HashSet;
+use bitset::PointSet;
 
 pub trait Area {
     fn area(&self) -> u32;
@@ -11,7 +11,7 @@ pub trait Area {
     fn touches_plant(&self, point: &Point, direction: &Direction) -> bool;
 }
 
-impl Area for HashSet<Point> {
+impl Area for PointSet {
     fn area(&self) -> u32 {
         let area = self.len() as u32;
         area
@@ -20,12 +20,13 @@ impl Area for HashSet<Point> {
     fn perimeter(&self) -> u32 {
         let perimeter = self
             .iter()
-            .map(|point| {
+            .map(|(x, y)| {
+                let point = Point { x, y };
                 STRAIGHT
                     .iter()
                     .map(|direction| match point.gen_in_direction(direction) {
                         None => 1,
-                        Some(gen_point) => match self.contains(&gen_point) {
+                        Some(gen_point) => match self.contains(gen_point.x, gen_point.y) {
                             true => 0,
                             false => 1,
                         },
@@ -41,7 +42,8 @@ impl Area for HashSet<Point> {
     fn sides(&self) -> u32 {
         let sides: u32 = self
             .iter()
-            .map(|point| {
+            .map(|(x, y)| {
+                let point = &Point { x, y };
                 let vertical = [North, South]
                     .iter()
                     .filter(|direction| self.touches_plant(point, direction))
@@ -102,7 +104,7 @@ impl Area for HashSet<Point> {
     fn touches_plant(&self, point: &Point, direction: &Direction) -> bool {
         match point.gen_in_direction(direction) {
             None => false,
-            Some(gen_point) => match self.contains(&gen_point) {
+            Some(gen_point) => match self.contains(gen_point.x, gen_point.y) {
                 true => true,
                 false => false,
             },
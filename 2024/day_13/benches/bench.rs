@@ -0,0 +1,21 @@
+use std::str::FromStr;
+use y2024_day_13::{PrizeSearch, Puzzle, PRIZE_SEARCHES};
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench(args = PRIZE_SEARCHES)]
+fn part1(search: PrizeSearch) {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR"))).unwrap();
+    let puzzle = Puzzle::from_str(&input).unwrap();
+    puzzle.part_1_with(search);
+}
+
+#[divan::bench(args = PRIZE_SEARCHES)]
+fn part2(search: PrizeSearch) {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    let puzzle = Puzzle::from_str(&input).unwrap();
+    puzzle.part_2_with(search);
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+use std::str::FromStr;
+use y2024_day_13::Puzzle;
+
+fn main() -> eyre::Result<()> {
+    let input = vault::read_input(".")?;
+    let input = parsing::normalise(&input);
+
+    let puzzle = Puzzle::from_str(&input).unwrap();
+
+    println!("Part 2: {}", puzzle.part_2());
+
+    Ok(())
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use eyre::Context;
 use itertools::Itertools;
-use regex::Regex;
+use parsing::{ParseError, Segment};
 use std::cmp::{min, Ordering};
 use std::str::FromStr;
 use float_cmp::approx_eq;
@@ -57,54 +57,57 @@ enum Direction{
 }
 
 impl FromStr for Puzzle {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let regex = Regex::new(r"X.(\d*)|Y.(\d*)").expect("Hardcode regex should be valid");
-        let machines = input
-            .split("\n\n")
-            .filter_map(|machine_config| {
-                let captures = regex
-                    .captures_iter(machine_config)
-                    .filter_map(|capture| {
-                        capture
-                            .get(1)
-                            .or_else(|| capture.get(2))
-                            .map(|x| x.as_str())
-                    })
-                    .filter_map(|x| x.parse().ok())
-                    .collect::<Vec<i64>>();
-
-                let button_a = Button::a(captures.get(0)?.clone(), captures.get(1)?.clone());
-                let button_b = Button::b(captures.get(2)?.clone(), captures.get(3)?.clone());
-                let prize = Point {
-                    x: captures.get(4)?.clone(),
-                    y: captures.get(5)?.clone(),
-                };
-
-                Some(ClawMachine {
-                    a: button_a,
-                    b: button_b,
-                    prize,
+        let machines = Segment::new(input)
+            .blocks()
+            .map(|machine_config| {
+                let [a_x, a_y, b_x, b_y, x, y] = machine_config.integer_array()?;
+
+                Ok(ClawMachine {
+                    a: Button::a(a_x, a_y),
+                    b: Button::b(b_x, b_y),
+                    prize: Point { x, y },
                 })
             })
-            .collect::<Vec<ClawMachine>>();
+            .collect::<Result<Vec<ClawMachine>, ParseError>>()?;
 
         Ok(Puzzle { machines })
     }
 }
 
+/// The ways of finding how many presses win a prize, kept side by side to compare them.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PrizeSearch {
+    /// Binary searches the B presses for the count where both axes need the same A presses.
+    BinarySearch,
+    /// Solves the two linear equations exactly with Cramer's rule.
+    Algebraic,
+}
+
+pub const PRIZE_SEARCHES: [PrizeSearch; 2] = [PrizeSearch::BinarySearch, PrizeSearch::Algebraic];
+
 impl Puzzle {
     pub fn part_1(&self) -> i64 {
+        self.part_1_with(PrizeSearch::Algebraic)
+    }
+
+    pub fn part_2(&self) -> i64 {
+        self.part_2_with(PrizeSearch::Algebraic)
+    }
+
+    pub fn part_1_with(&self, search: PrizeSearch) -> i64 {
         self.machines
             .iter()
-            .filter_map(|machine| {
-                Self::prize_cost(machine, Some(100))
+            .filter_map(|machine| match search {
+                PrizeSearch::BinarySearch => Self::prize_cost(machine, Some(100)),
+                PrizeSearch::Algebraic => Self::exact_prize_cost(machine, Some(100)),
             })
             .sum()
     }
 
-    pub fn part_2(&self) -> i64 {
+    pub fn part_2_with(&self, search: PrizeSearch) -> i64 {
         let increment = 10000000000000_i64;
         let mut machines = self.machines.clone();
 
@@ -115,12 +118,32 @@ impl Puzzle {
 
         machines
             .iter()
-            .filter_map(|machine| {
-                Self::prize_cost(machine, None)
+            .filter_map(|machine| match search {
+                PrizeSearch::BinarySearch => Self::prize_cost(machine, None),
+                PrizeSearch::Algebraic => Self::exact_prize_cost(machine, None),
             })
             .sum()
     }
 
+    fn exact_prize_cost(machine: &ClawMachine, ceiling: Option<i64>) -> Option<i64> {
+        let (a, b, prize) = (&machine.a.point, &machine.b.point, &machine.prize);
+        let determinant = a.x * b.y - a.y * b.x;
+        if determinant == 0 {
+            return None;
+        }
+
+        let a_numerator = prize.x * b.y - prize.y * b.x;
+        let b_numerator = a.x * prize.y - a.y * prize.x;
+        if a_numerator % determinant != 0 || b_numerator % determinant != 0 {
+            return None;
+        }
+
+        let (a_presses, b_presses) = (a_numerator / determinant, b_numerator / determinant);
+        let within_ceiling = ceiling.is_none_or(|x| a_presses <= x && b_presses <= x);
+        (a_presses >= 0 && b_presses >= 0 && within_ceiling)
+            .then(|| a_presses * machine.a.cost + b_presses * machine.b.cost)
+    }
+
     fn prize_cost(machine: &ClawMachine, ceiling: Option<i64>) -> Option<i64> {
         let prize_x_by_b = machine.prize.x / machine.b.point.x;
         let prize_y_by_b = machine.prize.y / machine.b.point.y;
@@ -217,10 +240,10 @@ Prize: X=18641, Y=10279";
         let puzzle = Puzzle::from_str(INPUT).unwrap();
 
         // When
-        let sum = puzzle.part_1();
+        let sums = PRIZE_SEARCHES.map(|x| puzzle.part_1_with(x));
 
         // Then
-        assert_eq!(480, sum);
+        assert_eq!([480, 480], sums);
     }
 
     #[test]
@@ -229,9 +252,22 @@ Prize: X=18641, Y=10279";
         let puzzle = Puzzle::from_str(INPUT).unwrap();
 
         // When
-        let sum = puzzle.part_2();
+        let sums = PRIZE_SEARCHES.map(|x| puzzle.part_2_with(x));
+
+        // Then
+        assert_eq!([875318608908, 875318608908], sums);
+    }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::from_str(&parsing::normalise(&x)).unwrap().part_1());
 
         // Then
-        assert_eq!(875318608908, sum);
+        assert_eq!([480, 480], sums);
     }
 }
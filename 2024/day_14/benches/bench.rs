@@ -0,0 +1,22 @@
+use std::str::FromStr;
+use y2024_day_14::{Point, Puzzle};
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+#[divan::bench]
+fn part1() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR"))).unwrap();
+    let puzzle = Puzzle::from_str(&input).unwrap();
+    puzzle.part_1(Point::new(100, 102));
+}
+
+#[divan::bench]
+#[ignore]
+fn part2() {
+    let input = divan::black_box(vault::read_input(env!("CARGO_MANIFEST_DIR")).unwrap());
+    let puzzle = Puzzle::from_str(&input).unwrap();
+    puzzle.part_2(Point::new(100, 102));
+}
\ No newline at end of file
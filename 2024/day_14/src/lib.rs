@@ -1,3 +1,4 @@
+use parsing::{ParseError, Segment};
 use std::str::FromStr;
 
 #[derive(Debug, Clone)]
@@ -56,6 +57,21 @@ impl Robot {
             velocity: self.velocity,
         }
     }
+
+    /// Where the robot is after `seconds`, wrapping around the grid.
+    fn after(&self, dimensions: &Point, seconds: u32) -> Robot {
+        let wrap = |position: i32, velocity: i32, size: i32| {
+            (i64::from(position) + i64::from(velocity) * i64::from(seconds)).rem_euclid(i64::from(size) + 1) as i32
+        };
+
+        Robot {
+            position: Point {
+                x: wrap(self.position.x, self.velocity.x, dimensions.x),
+                y: wrap(self.position.y, self.velocity.y, dimensions.y),
+            },
+            velocity: self.velocity.clone(),
+        }
+    }
 }
 
 pub struct Puzzle {
@@ -63,28 +79,20 @@ pub struct Puzzle {
 }
 
 impl FromStr for Puzzle {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let robots = input
+        let robots = Segment::new(input)
             .lines()
-            .filter_map(|line| {
-                let mut nums = line
-                    .split(|x| x == '=' || x == ',' || x == ' ')
-                    .filter_map(|p| p.parse::<i32>().ok());
-
-                Some(Robot {
-                    position: Point {
-                        x: nums.next()?,
-                        y: nums.next()?,
-                    },
-                    velocity: Point {
-                        x: nums.next()?,
-                        y: nums.next()?,
-                    },
+            .map(|line| {
+                let [x, y, velocity_x, velocity_y] = line.integer_array()?;
+
+                Ok(Robot {
+                    position: Point::new(x, y),
+                    velocity: Point::new(velocity_x, velocity_y),
                 })
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, ParseError>>()?;
 
         Ok(Puzzle { robots })
     }
@@ -111,8 +119,32 @@ impl Puzzle {
         }).product()
     }
 
-    fn snapshot(dimensions: &Point, robots: &Vec<Robot>) {
-        let lines = (0..dimensions.y + 1)
+    /// How many robots are in each quadrant after `seconds`, multiplied together.
+    pub fn safety_factor_at(&self, dimensions: &Point, seconds: u32) -> u32 {
+        let robots = self.robots.iter().map(|x| x.after(dimensions, seconds)).collect::<Vec<_>>();
+
+        dimensions
+            .quadrants()
+            .iter()
+            .map(|(floor, ceiling)| {
+                robots
+                    .iter()
+                    .filter(|robot| robot.position.x >= floor.x && robot.position.x <= ceiling.x &&
+                        robot.position.y >= floor.y && robot.position.y <= ceiling.y)
+                    .count() as u32
+            })
+            .product()
+    }
+
+    /// The grid after `seconds`, with the number of robots on each tile or `.` for none.
+    pub fn render_at(&self, dimensions: &Point, seconds: u32) -> String {
+        let robots = self.robots.iter().map(|x| x.after(dimensions, seconds)).collect::<Vec<_>>();
+
+        Self::render(dimensions, &robots)
+    }
+
+    fn render(dimensions: &Point, robots: &[Robot]) -> String {
+        (0..dimensions.y + 1)
             .map(|y| (0..dimensions.x + 1)
                 .map(|x| {
                     let robots_at_position = robots
@@ -122,11 +154,13 @@ impl Puzzle {
                         0 => ".".to_string(),
                         _ => { robots_at_position.to_string() }
                     }
-                }).collect::<Vec<String>>()
-                .join(""))
-            .collect::<Vec<_>>();
+                }).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 
-        lines.iter().for_each(|line| println!("{}", line));
+    fn snapshot(dimensions: &Point, robots: &Vec<Robot>) {
+        println!("{}", Self::render(dimensions, robots));
     }
 
     pub fn part_2(&self, dimensions: Point) -> u32 {
@@ -180,4 +214,33 @@ p=9,5 v=-3,-3";
         // Then
         assert_eq!(1, sum);
     }
+
+    #[test]
+    fn should_place_robots_at_any_time() {
+        // Given
+        let dimensions = Point::new(10, 6);
+        let puzzle = Puzzle::from_str(INPUT).unwrap();
+        let smol = Puzzle::from_str(SMOL).unwrap();
+
+        // When
+        let safety = puzzle.safety_factor_at(&dimensions, 100);
+        let grid = smol.render_at(&dimensions, 5);
+
+        // Then
+        assert_eq!(12, safety);
+        assert_eq!("...........\n...........\n...........\n.1.........\n...........\n...........\n...........", grid);
+    }
+
+    #[test]
+    fn should_solve_crlf_and_bom_inputs() {
+        // Given
+        let crlf = INPUT.replace('\n', "\r\n") + "\r\n";
+        let inputs = [crlf.clone(), format!("\u{feff}{}", crlf)];
+
+        // When
+        let sums = inputs.map(|x| Puzzle::from_str(&parsing::normalise(&x)).unwrap().part_1(Point::new(10, 6)));
+
+        // Then
+        assert_eq!([12, 12], sums);
+    }
 }
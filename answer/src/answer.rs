@@ -0,0 +1,176 @@
+use crate::letters::{self, UnknownGlyph};
+use serde::{Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A puzzle answer. Integers are stored by value rather than by the width a solver happened to
+/// use, so `41_i32` and `41_usize` make the same answer: non-negative integers are `Unsigned`,
+/// negative ones `Signed`, and anything outside 64 bits is `Big`.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    /// An integer too wide for 64 bits, as canonical decimal digits.
+    Big(String),
+    Text(String),
+    /// Letters read from an answer drawn as ASCII art.
+    Letters(String),
+}
+
+impl Answer {
+    /// Reads the letters of an answer drawn with `#` or `█`, such as a screen of pixels.
+    pub fn from_ascii_art(art: &str) -> Result<Answer, UnknownGlyph> {
+        letters::recognise(art).map(Answer::Letters)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        matches!(self, Answer::Signed(_) | Answer::Unsigned(_) | Answer::Big(_))
+    }
+}
+
+/// Letters read from ASCII art are the same answer as the same text typed out.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_integer() == other.is_integer() && self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Answer {}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match (u64::try_from(value), i64::try_from(value)) {
+            (Ok(value), _) => Answer::Unsigned(value),
+            (_, Ok(value)) => Answer::Signed(value),
+            _ => Answer::Big(value.to_string()),
+        }
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match u64::try_from(value) {
+            Ok(value) => Answer::Unsigned(value),
+            Err(_) => Answer::Big(value.to_string()),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($integer:ty => $wide:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::from(<$wide>::try_from(value).expect("Integer should fit in 128 bits"))
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8 => i128, i16 => i128, i32 => i128, i64 => i128, isize => i128);
+from_integer!(u8 => u128, u16 => u128, u32 => u128, u64 => u128, usize => u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// Canonical digits for an integer of any length, e.g. `-0012` is `-12`, or `None` if `text`
+/// isn't an integer.
+fn canonical_integer(text: &str) -> Option<String> {
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", text.strip_prefix('+').unwrap_or(text)),
+    };
+    if digits.is_empty() || !digits.bytes().all(|x| x.is_ascii_digit()) {
+        return None;
+    }
+
+    match digits.trim_start_matches('0') {
+        "" => Some("0".to_string()),
+        digits => Some(format!("{}{}", sign, digits)),
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Reads integers of any width as integers, and anything else as text.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(match canonical_integer(text) {
+            Some(digits) => match digits.parse::<i128>() {
+                Ok(value) => Answer::from(value),
+                Err(_) => match digits.parse::<u128>() {
+                    Ok(value) => Answer::from(value),
+                    Err(_) => Answer::Big(digits),
+                },
+            },
+            None => Answer::Text(text.to_string()),
+        })
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Big(digits) | Answer::Text(digits) | Answer::Letters(digits) => write!(f, "{}", digits),
+        }
+    }
+}
+
+/// Integers that fit in 64 bits become JSON numbers, everything else a string.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Signed(value) => serializer.serialize_i64(*value),
+            Answer::Unsigned(value) => serializer.serialize_u64(*value),
+            Answer::Big(text) | Answer::Text(text) | Answer::Letters(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_make_the_same_answer_from_any_integer_width() {
+        // When
+        let answers = [Answer::from(41_i32), Answer::from(41_usize), Answer::from(41_i64), "041".parse().unwrap()];
+
+        // Then
+        assert!(answers.iter().all(|x| *x == Answer::Unsigned(41)));
+        assert_eq!(Answer::Signed(-7), Answer::from(-7_i32));
+        assert_eq!(Answer::Big("-170141183460469231731687303715884105728".to_string()), Answer::from(i128::MIN));
+        assert_eq!(
+            Answer::Big("1000000000000000000000000000000000000000".to_string()),
+            "1000000000000000000000000000000000000000".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_display_and_read_text() {
+        // Given
+        let answer = Answer::from("4,6,3,5,6,3,5,2,1,0");
+
+        // When
+        let text = answer.to_string();
+
+        // Then
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", text);
+        assert_eq!(answer, text.parse().unwrap());
+        assert!(!answer.is_integer());
+        assert_eq!(Answer::Letters("HER".to_string()), Answer::from("HER"));
+    }
+}
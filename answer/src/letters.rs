@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The letters of the 4x6 font some puzzles draw their answers in, one row per line.
+const GLYPHS: [(char, &str); 16] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// A shape in drawn letters that isn't in the font, along with the column it starts at.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UnknownGlyph {
+    pub column: usize,
+    pub glyph: String,
+}
+
+impl Display for UnknownGlyph {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown letter at column {}:\n{}", self.column, self.glyph)
+    }
+}
+
+impl Error for UnknownGlyph {}
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█')
+}
+
+/// Reads letters drawn with `#` or `█` on a background of `.` or spaces. Letters are told apart
+/// by the blank columns between them, and blank rows around the drawing are ignored.
+pub fn recognise(art: &str) -> Result<String, UnknownGlyph> {
+    let rows = art
+        .lines()
+        .map(|x| x.chars().map(is_lit).collect::<Vec<_>>())
+        .skip_while(|x| !x.contains(&true))
+        .collect::<Vec<_>>();
+    let height = rows.iter().rposition(|x| x.contains(&true)).map_or(0, |x| x + 1);
+    let rows = &rows[..height];
+    let width = rows.iter().map(|x| x.len()).max().unwrap_or(0);
+
+    let lit = |x: usize, y: usize| rows[y].get(x).copied().unwrap_or(false);
+    let blank_column = |x: usize| (0..height).all(|y| !lit(x, y));
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if blank_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !blank_column(x) {
+            x += 1;
+        }
+
+        let glyph = (0..height)
+            .map(|y| (start..x).map(|x| if lit(x, y) { '#' } else { '.' }).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match GLYPHS.iter().find(|(_, shape)| *shape == glyph) {
+            Some((letter, _)) => letters.push(*letter),
+            None => return Err(UnknownGlyph { column: start, glyph }),
+        }
+    }
+
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_recognise_drawn_letters() {
+        // Given
+        let art = "
+#..#.####.###..
+#..#.#....#..#.
+####.###..#..#.
+#..#.#....###..
+#..#.#....#.#..
+#..#.####.#..#.
+";
+
+        // When
+        let letters = recognise(art);
+
+        // Then
+        assert_eq!(Ok("HER".to_string()), letters);
+    }
+
+    #[test]
+    fn should_reject_unknown_letters() {
+        // Given
+        let art = "█  █ ███\n█  █  █ \n████  █ \n█  █  █ \n█  █  █ \n█  █ ███";
+
+        // When
+        let letters = recognise(art);
+
+        // Then
+        assert_eq!(5, letters.unwrap_err().column);
+    }
+}
@@ -0,0 +1,5 @@
+mod answer;
+mod letters;
+
+pub use answer::Answer;
+pub use letters::{recognise, UnknownGlyph};
@@ -0,0 +1,120 @@
+use answer::Answer;
+use eyre::{eyre, WrapErr};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The accepted answers for a day, stored as `part_1: <answer>` lines in `answers.txt`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct AnswerBook {
+    answers: BTreeMap<u8, Answer>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Verdict<'a> {
+    Correct,
+    Incorrect { expected: &'a Answer },
+    Unknown,
+}
+
+impl AnswerBook {
+    pub fn load(path: &Path) -> eyre::Result<AnswerBook> {
+        match vault::read(path)? {
+            Some(contents) => contents.parse(),
+            None => Ok(AnswerBook::default()),
+        }
+    }
+
+    /// Writes the book as plaintext, for `aoc vault encrypt` to pick up.
+    pub fn save(&self, path: &Path) -> eyre::Result<()> {
+        fs::write(path, self.to_string()).wrap_err_with(|| format!("Could not write {}", path.display()))
+    }
+
+    pub fn get(&self, part: u8) -> Option<&Answer> {
+        self.answers.get(&part)
+    }
+
+    pub fn set(&mut self, part: u8, answer: impl Into<Answer>) {
+        self.answers.insert(part, answer.into());
+    }
+
+    pub fn check(&self, part: u8, answer: &Answer) -> Verdict<'_> {
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Incorrect { expected },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl FromStr for AnswerBook {
+    type Err = eyre::Report;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let answers = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (part, answer) = line
+                    .split_once(':')
+                    .ok_or_else(|| eyre!("Answer book line {:?} is missing ':'", line))?;
+                let part = part
+                    .trim()
+                    .strip_prefix("part_")
+                    .and_then(|x| x.parse::<u8>().ok())
+                    .ok_or_else(|| eyre!("Answer book line {:?} has no part number", line))?;
+
+                let answer = answer.trim().parse().unwrap_or_else(|x| match x {});
+
+                Ok((part, answer))
+            })
+            .collect::<eyre::Result<_>>()?;
+
+        Ok(AnswerBook { answers })
+    }
+}
+
+impl Display for AnswerBook {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.answers
+            .iter()
+            .try_for_each(|(part, answer)| writeln!(f, "part_{}: {}", part, answer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_answer_book() {
+        // Given
+        let input = "part_1: 11\npart_2: 31\n";
+
+        // When
+        let book = AnswerBook::from_str(input).unwrap();
+
+        // Then
+        assert_eq!(Some(&Answer::from(31)), book.get(2));
+        assert_eq!(input, book.to_string());
+    }
+
+    #[test]
+    fn should_check_answers_against_book() {
+        // Given
+        let mut book = AnswerBook::default();
+        book.set(1, 11);
+
+        // When
+        let correct = book.check(1, &Answer::from(11_usize));
+        let incorrect = book.check(1, &Answer::from(12));
+        let unknown = book.check(2, &Answer::from(31));
+
+        // Then
+        assert_eq!(Verdict::Correct, correct);
+        assert_eq!(Verdict::Incorrect { expected: &Answer::from(11) }, incorrect);
+        assert_eq!(Verdict::Unknown, unknown);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::workspace::Workspace;
+use eyre::{eyre, WrapErr};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How long one part took on its real input.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Timing {
+    pub day: u8,
+    pub part: u8,
+    pub elapsed: Duration,
+}
+
+/// Time allowed for the whole year, single days and single parts, read from the year's
+/// `budgets.txt` as
+/// `total: 1s`, `day_12: 50ms` and `day_06 part_2: 100ms` lines.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Budgets {
+    total: Option<Duration>,
+    days: BTreeMap<u8, Duration>,
+    parts: BTreeMap<(u8, u8), Duration>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Scope {
+    Total,
+    Day(u8),
+    Part(u8, u8),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Overrun {
+    pub scope: Scope,
+    pub elapsed: Duration,
+    pub budget: Duration,
+}
+
+fn parse_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    let (value, unit) = text.split_at(text.find(|c: char| !c.is_ascii_digit())?);
+    let value = value.parse().ok()?;
+
+    match unit {
+        "us" | "µs" => Some(Duration::from_micros(value)),
+        "ms" => Some(Duration::from_millis(value)),
+        "s" => Some(Duration::from_secs(value)),
+        _ => None,
+    }
+}
+
+impl Budgets {
+    pub fn load(workspace: &Workspace, year: u16) -> eyre::Result<Budgets> {
+        let path = workspace.budgets_path(year);
+        fs::read_to_string(&path)
+            .wrap_err_with(|| format!("Could not read {}", path.display()))?
+            .parse()
+    }
+
+    /// Every budget the timings exceed, largest overrun first.
+    pub fn check(&self, timings: &[Timing]) -> Vec<Overrun> {
+        let mut days: BTreeMap<u8, Duration> = BTreeMap::new();
+        timings.iter().for_each(|x| *days.entry(x.day).or_default() += x.elapsed);
+
+        let total = self.total.map(|budget| Overrun {
+            scope: Scope::Total,
+            elapsed: timings.iter().map(|x| x.elapsed).sum(),
+            budget,
+        });
+        let days = self.days.iter().filter_map(|(day, budget)| {
+            days.get(day).map(|elapsed| Overrun {
+                scope: Scope::Day(*day),
+                elapsed: *elapsed,
+                budget: *budget,
+            })
+        });
+        let parts = self.parts.iter().filter_map(|((day, part), budget)| {
+            timings
+                .iter()
+                .find(|x| x.day == *day && x.part == *part)
+                .map(|x| Overrun {
+                    scope: Scope::Part(*day, *part),
+                    elapsed: x.elapsed,
+                    budget: *budget,
+                })
+        });
+
+        let mut overruns = total
+            .into_iter()
+            .chain(days)
+            .chain(parts)
+            .filter(|x| x.elapsed > x.budget)
+            .collect::<Vec<_>>();
+        overruns.sort_by_key(|x| std::cmp::Reverse(x.elapsed - x.budget));
+
+        overruns
+    }
+
+    /// The budget closest to a part, its own before its day's, shown with `(day)` for a day's.
+    fn budget_for(&self, timing: &Timing) -> Option<String> {
+        match self.parts.get(&(timing.day, timing.part)) {
+            Some(budget) => Some(format!("{:.2?}", budget)),
+            None => self.days.get(&timing.day).map(|x| format!("{:.2?} (day)", x)),
+        }
+    }
+
+    /// Every part ranked from slowest to fastest, with its share of the total and any budget
+    /// that applies to it.
+    pub fn breakdown(&self, timings: &[Timing], overruns: &[Overrun]) -> String {
+        let total: Duration = timings.iter().map(|x| x.elapsed).sum();
+        let mut ranked = timings.to_vec();
+        ranked.sort_by_key(|x| std::cmp::Reverse(x.elapsed));
+
+        let mut breakdown = String::new();
+        for overrun in overruns {
+            let scope = match overrun.scope {
+                Scope::Total => "Total".to_string(),
+                Scope::Day(day) => format!("Day {}", day),
+                Scope::Part(day, part) => format!("Day {} part {}", day, part),
+            };
+            let _ = writeln!(
+                breakdown,
+                "{} took {:.2?}, over its {:.2?} budget by {:.2?}",
+                scope,
+                overrun.elapsed,
+                overrun.budget,
+                overrun.elapsed - overrun.budget
+            );
+        }
+
+        let _ = writeln!(breakdown, "\n{:>4}  {:>3}  {:>4}  {:>10}  {:>5}  Budget", "Rank", "Day", "Part", "Time", "Share");
+        for (rank, timing) in ranked.iter().enumerate() {
+            let share = match total.is_zero() {
+                true => 0.0,
+                false => timing.elapsed.as_secs_f64() / total.as_secs_f64() * 100.0,
+            };
+            let over = overruns.iter().any(|x| match x.scope {
+                Scope::Total => false,
+                Scope::Day(day) => day == timing.day,
+                Scope::Part(day, part) => day == timing.day && part == timing.part,
+            });
+            let budget = self.budget_for(timing).unwrap_or_else(|| "-".to_string());
+
+            let _ = writeln!(
+                breakdown,
+                "{:>4}  {:>3}  {:>4}  {:>10}  {:>4.1}%  {}{}",
+                rank + 1,
+                timing.day,
+                timing.part,
+                format!("{:.2?}", timing.elapsed),
+                share,
+                budget,
+                if over { "  OVER" } else { "" }
+            );
+        }
+        let _ = writeln!(breakdown, "\nTotal: {:.2?}", total);
+
+        breakdown
+    }
+}
+
+impl FromStr for Budgets {
+    type Err = eyre::Report;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut budgets = Budgets::default();
+
+        for line in input.lines().map(|x| x.trim()).filter(|x| !x.is_empty() && !x.starts_with('#')) {
+            let (scope, duration) = line
+                .split_once(':')
+                .ok_or_else(|| eyre!("Budget line {:?} is missing ':'", line))?;
+            let duration =
+                parse_duration(duration).ok_or_else(|| eyre!("Budget line {:?} has an invalid duration", line))?;
+            let invalid = || eyre!("Budget line {:?} should name total, day_NN or day_NN part_N", line);
+
+            match scope.split_whitespace().collect::<Vec<_>>()[..] {
+                ["total"] => budgets.total = Some(duration),
+                [day] => {
+                    let day = day.strip_prefix("day_").and_then(|x| x.parse().ok()).ok_or_else(invalid)?;
+                    budgets.days.insert(day, duration);
+                }
+                [day, part] => {
+                    let day = day.strip_prefix("day_").and_then(|x| x.parse().ok()).ok_or_else(invalid)?;
+                    let part = part.strip_prefix("part_").and_then(|x| x.parse().ok()).ok_or_else(invalid)?;
+                    budgets.parts.insert((day, part), duration);
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(budgets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: u8, part: u8, millis: u64) -> Timing {
+        Timing {
+            day,
+            part,
+            elapsed: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn should_parse_budgets() {
+        // Given
+        let input = "# comment\ntotal: 1s\nday_12: 50ms\nday_06 part_2: 100ms\n";
+
+        // When
+        let budgets = Budgets::from_str(input).unwrap();
+
+        // Then
+        assert_eq!(Some(Duration::from_secs(1)), budgets.total);
+        assert_eq!(Some(&Duration::from_millis(50)), budgets.days.get(&12));
+        assert_eq!(Some(&Duration::from_millis(100)), budgets.parts.get(&(6, 2)));
+        assert!(Budgets::from_str("day_06 part_2: fast").is_err());
+    }
+
+    #[test]
+    fn should_rank_overruns_by_how_far_over_they_are() {
+        // Given
+        let budgets = Budgets::from_str("total: 300ms\nday_12: 50ms\nday_06 part_2: 100ms\n").unwrap();
+        let timings = [timing(6, 1, 10), timing(6, 2, 120), timing(12, 1, 40), timing(12, 2, 40)];
+
+        // When
+        let overruns = budgets.check(&timings);
+        let breakdown = budgets.breakdown(&timings, &overruns);
+
+        // Then
+        assert_eq!(
+            vec![Scope::Day(12), Scope::Part(6, 2)],
+            overruns.iter().map(|x| x.scope).collect::<Vec<_>>()
+        );
+        assert!(breakdown.contains("   1    6     2    120.00ms  57.1%  100.00ms  OVER"));
+        assert!(breakdown.contains("   2   12     1     40.00ms  19.0%  50.00ms (day)  OVER"));
+        assert!(breakdown.contains("   4    6     1     10.00ms   4.8%  -\n"));
+    }
+}
@@ -0,0 +1,430 @@
+use crate::escape;
+use crate::registry::{Arguments, Day, PartRun, Variant};
+use crate::workspace::Workspace;
+use answer::Answer;
+use eyre::{eyre, WrapErr};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+fn hex_digest(hasher: Sha256) -> String {
+    hasher.finalize().iter().map(|x| format!("{:02x}", x)).collect()
+}
+
+pub fn hash_input(input: &str) -> String {
+    hex_digest(Sha256::new_with_prefix(input))
+}
+
+/// The `path` of every local dependency in a crate's manifest, whether written inline or as a
+/// `[dependencies.x]` table.
+fn path_dependencies(manifest: &str) -> eyre::Result<Vec<String>> {
+    let manifest = manifest.parse::<toml::Table>()?;
+    let paths = ["dependencies", "dev-dependencies", "build-dependencies"]
+        .iter()
+        .filter_map(|x| manifest.get(*x).and_then(toml::Value::as_table))
+        .flat_map(|x| x.values())
+        .filter_map(|x| x.get("path").and_then(toml::Value::as_str))
+        .map(str::to_string)
+        .collect();
+
+    Ok(paths)
+}
+
+fn source_files(dir: &Path, files: &mut Vec<PathBuf>) -> eyre::Result<()> {
+    for entry in fs::read_dir(dir).wrap_err_with(|| format!("Could not read {}", dir.display()))? {
+        let path = entry?.path();
+        match path.is_dir() {
+            true => source_files(&path, files)?,
+            false => files.push(path),
+        }
+    }
+
+    Ok(())
+}
+
+/// Hashes the manifest and `src` of a day's crate and of every local crate it depends on, so
+/// that editing a shared crate like `parsing` invalidates the answers of the days using it, along
+/// with the registry that turns a day's input and arguments into calls to its solvers.
+pub fn fingerprint(workspace: &Workspace, year: u16, day: u8) -> eyre::Result<String> {
+    let mut pending = vec![workspace.day_dir(year, day).canonicalize()?];
+    let mut crates = BTreeSet::new();
+
+    while let Some(dir) = pending.pop() {
+        if !crates.insert(dir.clone()) {
+            continue;
+        }
+
+        let manifest = fs::read_to_string(dir.join("Cargo.toml"))
+            .wrap_err_with(|| format!("Could not read {}", dir.join("Cargo.toml").display()))?;
+        let dependencies = path_dependencies(&manifest)
+            .wrap_err_with(|| format!("Could not parse {}", dir.join("Cargo.toml").display()))?;
+        for dependency in dependencies {
+            pending.push(dir.join(dependency).canonicalize()?);
+        }
+    }
+
+    let mut files = Vec::new();
+    for dir in crates {
+        files.push(dir.join("Cargo.toml"));
+        source_files(&dir.join("src"), &mut files)?;
+    }
+    for path in workspace.registry_sources() {
+        match path.is_dir() {
+            true => source_files(&path, &mut files)?,
+            false => files.push(path),
+        }
+    }
+
+    let root = workspace.root().canonicalize()?;
+    let mut hasher = Sha256::new();
+    for file in files {
+        let file = file.canonicalize().wrap_err_with(|| format!("Could not read {}", file.display()))?;
+        let relative = file.strip_prefix(&root).unwrap_or(&file);
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(fs::read(&file)?);
+        hasher.update([0]);
+    }
+
+    Ok(hex_digest(hasher))
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Entry {
+    input_hash: String,
+    fingerprint: String,
+    arguments: String,
+    answer: Answer,
+    elapsed: Duration,
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct CacheStats {
+    pub hits: usize,
+    pub misses: usize,
+    /// How long the cached answers originally took to compute.
+    pub saved: Duration,
+}
+
+impl Display for CacheStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cache: {} hit{}, {} miss{}, saved {:.2?}",
+            self.hits,
+            if self.hits == 1 { "" } else { "s" },
+            self.misses,
+            if self.misses == 1 { "" } else { "es" },
+            self.saved
+        )
+    }
+}
+
+/// The last successful answer for each year, day, part and variant, stored as tab separated
+/// lines. An entry is only used while the input, the crate's source and the arguments are all
+/// unchanged.
+#[derive(Debug, Default)]
+pub struct AnswerCache {
+    entries: BTreeMap<(u16, u8, u8, String), Entry>,
+    stats: CacheStats,
+}
+
+/// What a part's cached answer is stored under, and what it was computed from.
+#[derive(Debug, Clone)]
+pub struct CacheKey {
+    id: (u16, u8, u8, String),
+    entry: Entry,
+}
+
+/// A part's result, and whether it came from the cache.
+#[derive(Debug)]
+pub struct CachedRun {
+    pub run: PartRun,
+    pub cached: bool,
+}
+
+impl AnswerCache {
+    pub fn load(path: &Path) -> eyre::Result<AnswerCache> {
+        match fs::read_to_string(path) {
+            // Anything that can't be read back, such as a cache from an older runner, is only a
+            // reason to solve again.
+            Ok(contents) => Ok(contents.parse().unwrap_or_default()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(AnswerCache::default()),
+            Err(e) => Err(e).wrap_err_with(|| format!("Could not read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> eyre::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.to_string()).wrap_err_with(|| format!("Could not write {}", path.display()))
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+
+    /// Identifies a part's answer along with everything it depends on.
+    pub fn key(
+        day: &Day,
+        part: u8,
+        variant: &Variant,
+        input: &str,
+        arguments: &Arguments,
+        fingerprint: &str,
+    ) -> CacheKey {
+        CacheKey {
+            id: (day.year, day.day, part, variant.name.to_string()),
+            entry: Entry {
+                input_hash: hash_input(input),
+                fingerprint: fingerprint.to_string(),
+                arguments: arguments.to_string(),
+                answer: Answer::from(""),
+                elapsed: Duration::ZERO,
+            },
+        }
+    }
+
+    /// Returns the cached answer when nothing it depends on has changed, counting a hit or a miss.
+    pub fn lookup(&mut self, key: &CacheKey) -> Option<PartRun> {
+        let Some(entry) = self.entries.get(&key.id).filter(|x| x.matches(&key.entry)) else {
+            self.stats.misses += 1;
+            return None;
+        };
+
+        self.stats.hits += 1;
+        self.stats.saved += entry.elapsed;
+
+        Some(PartRun {
+            part: key.id.2,
+            answer: Ok(entry.answer.clone()),
+            elapsed: entry.elapsed,
+        })
+    }
+
+    /// Caches the answer of a run that succeeded.
+    pub fn record(&mut self, key: CacheKey, run: &PartRun) {
+        if let Ok(answer) = &run.answer {
+            self.entries.insert(
+                key.id,
+                Entry {
+                    answer: answer.clone(),
+                    elapsed: run.elapsed,
+                    ..key.entry
+                },
+            );
+        }
+    }
+
+    /// Returns the cached answer when nothing it depends on has changed, otherwise solves the
+    /// part and caches the answer if it succeeded.
+    pub fn solve(
+        &mut self,
+        day: &Day,
+        part: u8,
+        variant: &Variant,
+        input: &str,
+        arguments: &Arguments,
+        fingerprint: &str,
+    ) -> CachedRun {
+        let key = AnswerCache::key(day, part, variant, input, arguments, fingerprint);
+        if let Some(run) = self.lookup(&key) {
+            return CachedRun { run, cached: true };
+        }
+
+        let run = day.solve_with(part, variant, input, arguments);
+        self.record(key, &run);
+
+        CachedRun { run, cached: false }
+    }
+}
+
+impl Entry {
+    fn matches(&self, key: &Entry) -> bool {
+        self.input_hash == key.input_hash && self.fingerprint == key.fingerprint && self.arguments == key.arguments
+    }
+}
+
+impl FromStr for AnswerCache {
+    type Err = eyre::Report;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut entries = BTreeMap::new();
+
+        for line in input.lines().filter(|x| !x.is_empty()) {
+            let fields = line.splitn(9, '\t').collect::<Vec<_>>();
+            let [year, day, part, variant, input_hash, fingerprint, arguments, nanos, answer] = fields[..] else {
+                return Err(eyre!("Cache line {:?} should have 9 fields", line));
+            };
+            let invalid = || eyre!("Cache line {:?} is invalid", line);
+
+            entries.insert(
+                (
+                    year.parse().map_err(|_| invalid())?,
+                    day.parse().map_err(|_| invalid())?,
+                    part.parse().map_err(|_| invalid())?,
+                    variant.to_string(),
+                ),
+                Entry {
+                    input_hash: input_hash.to_string(),
+                    fingerprint: fingerprint.to_string(),
+                    arguments: arguments.to_string(),
+                    answer: escape::unescape(answer).parse().unwrap_or_else(|x| match x {}),
+                    elapsed: Duration::from_nanos(nanos.parse().map_err(|_| invalid())?),
+                },
+            );
+        }
+
+        Ok(AnswerCache {
+            entries,
+            stats: CacheStats::default(),
+        })
+    }
+}
+
+impl Display for AnswerCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for ((year, day, part, variant), entry) in &self.entries {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                year,
+                day,
+                part,
+                variant,
+                entry.input_hash,
+                entry.fingerprint,
+                entry.arguments,
+                entry.elapsed.as_nanos(),
+                escape::escape(&entry.answer.to_string())
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    const INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    #[test]
+    fn should_hit_only_while_input_source_and_arguments_are_unchanged() {
+        // Given
+        let day = registry::day(2024, 1).unwrap();
+        let variant = day.variant(1, registry::DEFAULT_VARIANT).unwrap();
+        let arguments = day.arguments(&[]).unwrap();
+        let mut cache = AnswerCache::default();
+
+        // When
+        let first = cache.solve(day, 1, &variant, INPUT, &arguments, "source");
+        let second = cache.solve(day, 1, &variant, INPUT, &arguments, "source");
+        let edited_input = cache.solve(day, 1, &variant, "1   1\n", &arguments, "source");
+        let edited_source = cache.solve(day, 1, &variant, "1   1\n", &arguments, "edited");
+
+        // Then
+        assert!(!first.cached);
+        assert!(second.cached);
+        assert_eq!(Answer::from(11), second.run.answer.unwrap());
+        assert!(!edited_input.cached);
+        assert!(!edited_source.cached);
+        assert_eq!(1, cache.stats().hits);
+        assert_eq!(3, cache.stats().misses);
+    }
+
+    #[test]
+    fn should_round_trip_entries() {
+        // Given
+        let day = registry::day(2024, 1).unwrap();
+        let variant = day.variant(2, registry::DEFAULT_VARIANT).unwrap();
+        let mut cache = AnswerCache::default();
+        cache.solve(day, 2, &variant, INPUT, &day.arguments(&[]).unwrap(), "source");
+
+        // When
+        let mut loaded = AnswerCache::from_str(&cache.to_string()).unwrap();
+        let run = loaded.solve(day, 2, &variant, INPUT, &day.arguments(&[]).unwrap(), "source");
+
+        // Then
+        assert!(run.cached);
+        assert_eq!(Answer::from(31), run.run.answer.unwrap());
+    }
+
+    #[test]
+    fn should_round_trip_answers_with_escapes() {
+        // Given
+        let answer = Answer::from("line\nC:\\new\\nothing");
+        let mut cache = AnswerCache::default();
+        let entry = Entry {
+            input_hash: hash_input(INPUT),
+            fingerprint: "source".to_string(),
+            arguments: String::new(),
+            answer: answer.clone(),
+            elapsed: Duration::from_nanos(1),
+        };
+        cache.entries.insert((2024, 1, 1, registry::DEFAULT_VARIANT.to_string()), entry);
+
+        // When
+        let loaded = AnswerCache::from_str(&cache.to_string()).unwrap();
+
+        // Then
+        assert_eq!(1, cache.to_string().lines().count());
+        assert_eq!(answer, loaded.entries.values().next().unwrap().answer);
+    }
+
+    #[test]
+    fn should_fingerprint_local_dependencies() {
+        // Given
+        let manifest = "[dependencies]\neyre = \"0.6.12\"\nparsing = { path = \"../parsing\" }\n\n[[bench]]\npath = \"benches/bench.rs\"\n";
+
+        // When
+        let dependencies = path_dependencies(manifest).unwrap();
+
+        // Then
+        assert_eq!(vec!["../parsing"], dependencies);
+        assert_eq!(fingerprint(&Workspace::default(), 2024, 7).unwrap(), fingerprint(&Workspace::default(), 2024, 7).unwrap());
+    }
+
+    #[test]
+    fn should_find_local_dependencies_however_they_are_written() {
+        // Given
+        let manifest = "[dependencies]\nanswer = {path=\"../answer\"}\n\n[dependencies.parsing]\npath = \"../parsing\"\n\n[dev-dependencies]\nbitset = { version = \"0.1\", path = \"../bitset\" }\n";
+
+        // When
+        let dependencies = path_dependencies(manifest).unwrap();
+
+        // Then
+        assert_eq!(vec!["../answer", "../parsing", "../bitset"], dependencies);
+    }
+
+    #[test]
+    fn should_fingerprint_the_registry() {
+        // Given
+        let root = std::env::temp_dir().join(format!("aoc-fingerprint-{}", std::process::id()));
+        let workspace = Workspace::new(&root);
+        let [registry, year_dir] = workspace.registry_sources();
+        fs::create_dir_all(workspace.day_dir(2024, 2).join("src")).unwrap();
+        fs::create_dir_all(&year_dir).unwrap();
+        fs::write(workspace.day_dir(2024, 2).join("Cargo.toml"), "[package]\n").unwrap();
+        fs::write(workspace.day_dir(2024, 2).join("src").join("lib.rs"), "").unwrap();
+        fs::write(&registry, "mod y2024;\n").unwrap();
+        fs::write(year_dir.join("y2024.rs"), "count(input)").unwrap();
+
+        // When
+        let before = fingerprint(&workspace, 2024, 2).unwrap();
+        fs::write(year_dir.join("y2024.rs"), "count_tolerant(input, 1)").unwrap();
+        let after = fingerprint(&workspace, 2024, 2).unwrap();
+
+        // Then
+        assert_ne!(before, after);
+        fs::remove_dir_all(root).unwrap();
+    }
+}
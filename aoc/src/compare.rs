@@ -0,0 +1,449 @@
+use crate::answer_book::AnswerBook;
+use crate::registry::{Day, PartRun};
+use answer::Answer;
+use eyre::{eyre, WrapErr};
+use std::fmt::{Display, Write};
+use std::fs;
+use std::io::{self, Read};
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::{Command, ExitStatus, Stdio};
+use std::str::FromStr;
+use std::sync::mpsc::channel;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// A solver written outside this workspace, such as a teammate's Python or Go solution. Its
+/// command is split on whitespace, with `{year}`, `{day}`, `{day:02}` and `{input}` replaced in
+/// each word, and it should print `part_1: <answer>` and `part_2: <answer>` lines.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ExternalSolver {
+    pub name: String,
+    pub command: Vec<String>,
+}
+
+/// The external solvers listed in `solvers.txt` as `name: command` lines.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct SolverConfig {
+    pub solvers: Vec<ExternalSolver>,
+}
+
+impl SolverConfig {
+    pub fn load(path: &Path) -> eyre::Result<SolverConfig> {
+        fs::read_to_string(path)
+            .wrap_err_with(|| format!("Could not read {}, which should list `name: command` lines", path.display()))?
+            .parse()
+    }
+}
+
+impl FromStr for SolverConfig {
+    type Err = eyre::Report;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut solvers: Vec<ExternalSolver> = Vec::new();
+
+        for line in input.lines().map(str::trim).filter(|x| !x.is_empty() && !x.starts_with('#')) {
+            let (name, command) = line
+                .split_once(':')
+                .ok_or_else(|| eyre!("Solver line {:?} is missing ':'", line))?;
+            let name = name.trim().to_string();
+            let command = command.split_whitespace().map(str::to_string).collect::<Vec<_>>();
+
+            if name.is_empty() || command.is_empty() {
+                return Err(eyre!("Solver line {:?} should be `name: command`", line));
+            }
+            if name == RUST {
+                return Err(eyre!("Solver name {:?} is reserved for the Rust solvers", name));
+            }
+            if solvers.iter().any(|x| x.name == name) {
+                return Err(eyre!("Solver {:?} is named more than once", name));
+            }
+            solvers.push(ExternalSolver { name, command });
+        }
+
+        Ok(SolverConfig { solvers })
+    }
+}
+
+/// How an external solver's run ended.
+#[derive(Debug)]
+pub enum ExternalOutcome {
+    Answered(AnswerBook),
+    Failed(String),
+    TimedOut,
+}
+
+#[derive(Debug)]
+pub struct ExternalRun {
+    pub outcome: ExternalOutcome,
+    pub elapsed: Duration,
+    pub stderr: String,
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut bytes);
+        }
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+fn kill_group(leader: u32) {
+    // SAFETY: kill only sends a signal. The solver leads its own process group, so this reaches
+    // anything it started, such as an interpreter under a shell script, but never the runner.
+    unsafe {
+        libc::kill(-(leader as libc::pid_t), libc::SIGKILL);
+    }
+}
+
+/// Runs `command` with its output captured, killing it and everything it started once it has
+/// run for `timeout`. The status is `None` when it was killed.
+fn run_captured(command: &mut Command, timeout: Duration) -> io::Result<(Option<ExitStatus>, String, String, Duration)> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .process_group(0);
+
+    let start = Instant::now();
+    let mut child = command.spawn()?;
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let leader = child.id();
+    let (sender, receiver) = channel();
+    thread::spawn(move || sender.send(child.wait()));
+
+    let status = match receiver.recv_timeout(timeout) {
+        Ok(status) => Some(status?),
+        Err(_) => {
+            kill_group(leader);
+            let _ = receiver.recv();
+            None
+        }
+    };
+    let elapsed = start.elapsed();
+
+    let read = |x: JoinHandle<String>| x.join().unwrap_or_default();
+    Ok((status, read(stdout), read(stderr), elapsed))
+}
+
+impl ExternalSolver {
+    pub fn arguments(&self, year: u16, day: u8, input: &Path) -> Vec<String> {
+        self.command
+            .iter()
+            .map(|x| {
+                x.replace("{year}", &year.to_string())
+                    .replace("{day:02}", &format!("{:02}", day))
+                    .replace("{day}", &day.to_string())
+                    .replace("{input}", &input.to_string_lossy())
+            })
+            .collect()
+    }
+
+    pub fn run(&self, year: u16, day: u8, input: &Path, timeout: Duration) -> ExternalRun {
+        let arguments = self.arguments(year, day, input);
+        let mut command = Command::new(&arguments[0]);
+        command.args(&arguments[1..]);
+
+        let (status, stdout, stderr, elapsed) = match run_captured(&mut command, timeout) {
+            Ok(captured) => captured,
+            Err(e) => {
+                return ExternalRun {
+                    outcome: ExternalOutcome::Failed(format!("could not start {:?}: {}", arguments[0], e)),
+                    elapsed: Duration::ZERO,
+                    stderr: String::new(),
+                }
+            }
+        };
+
+        let outcome = match status {
+            None => ExternalOutcome::TimedOut,
+            Some(status) if !status.success() => ExternalOutcome::Failed(status.to_string()),
+            Some(_) => match stdout.parse::<AnswerBook>() {
+                Ok(answers) => ExternalOutcome::Answered(answers),
+                Err(e) => ExternalOutcome::Failed(e.to_string()),
+            },
+        };
+
+        ExternalRun { outcome, elapsed, stderr }
+    }
+}
+
+/// The name the Rust solvers are reported under.
+pub const RUST: &str = "rust";
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Agreement {
+    Agrees,
+    Mismatch,
+    Failed,
+    /// The Rust solver has no answer to compare with.
+    Unverified,
+}
+
+#[derive(Debug)]
+pub struct ExternalAnswer {
+    pub solver: String,
+    pub answer: Result<Answer, String>,
+    pub elapsed: Duration,
+}
+
+/// A part's Rust answer alongside each external solver's.
+#[derive(Debug)]
+pub struct PartComparison {
+    pub part: u8,
+    pub rust: PartRun,
+    pub external: Vec<ExternalAnswer>,
+}
+
+impl PartComparison {
+    pub fn agreement(&self, external: &ExternalAnswer) -> Agreement {
+        match (&self.rust.answer, &external.answer) {
+            (_, Err(_)) => Agreement::Failed,
+            (Err(_), Ok(_)) => Agreement::Unverified,
+            (Ok(rust), Ok(answer)) if rust == answer => Agreement::Agrees,
+            (Ok(_), Ok(_)) => Agreement::Mismatch,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DayComparison {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<PartComparison>,
+    /// What each external solver wrote to stderr, kept for runs that failed or timed out.
+    pub stderr: Vec<(String, String)>,
+}
+
+impl DayComparison {
+    /// The external answers that disagree with the Rust answer or are missing.
+    pub fn problems(&self) -> usize {
+        self.parts
+            .iter()
+            .flat_map(|part| part.external.iter().map(|x| part.agreement(x)))
+            .filter(|x| matches!(x, Agreement::Mismatch | Agreement::Failed))
+            .count()
+    }
+}
+
+/// Solves both parts of a day in Rust and with each external solver, which reads the same input
+/// from `input_path`.
+pub fn compare_day(
+    day: &Day,
+    input: &str,
+    input_path: &Path,
+    solvers: &[ExternalSolver],
+    timeout: Duration,
+) -> eyre::Result<DayComparison> {
+    let arguments = day.arguments(&[])?;
+    let runs = solvers
+        .iter()
+        .map(|x| (x, x.run(day.year, day.day, input_path, timeout)))
+        .collect::<Vec<_>>();
+
+    let parts = (1..=2)
+        .map(|part| PartComparison {
+            part,
+            rust: day.solve(part, input, &arguments),
+            external: runs
+                .iter()
+                .map(|(solver, run)| ExternalAnswer {
+                    solver: solver.name.clone(),
+                    answer: match &run.outcome {
+                        ExternalOutcome::Answered(answers) => answers
+                            .get(part)
+                            .cloned()
+                            .ok_or_else(|| format!("printed no answer for part {}", part)),
+                        ExternalOutcome::Failed(e) => Err(e.clone()),
+                        ExternalOutcome::TimedOut => Err(format!("timed out after {:?}", timeout)),
+                    },
+                    elapsed: run.elapsed,
+                })
+                .collect(),
+        })
+        .collect();
+
+    let stderr = runs
+        .into_iter()
+        .filter(|(_, run)| !matches!(run.outcome, ExternalOutcome::Answered(_)) && !run.stderr.trim().is_empty())
+        .map(|(solver, run)| (solver.name.clone(), run.stderr))
+        .collect();
+
+    Ok(DayComparison {
+        year: day.year,
+        day: day.day,
+        parts,
+        stderr,
+    })
+}
+
+fn row(report: &mut String, [day, part, solver, answer, time, relative, result]: [&str; 7]) {
+    let _ = writeln!(
+        report,
+        "{:>3}  {:>4}  {:<10}  {:<20}  {:>10}  {:>8}  {}",
+        day, part, solver, answer, time, relative, result
+    );
+}
+
+fn describe_answer<E: Display>(answer: &Result<Answer, E>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("error: {}", e),
+    }
+}
+
+/// Tabulates every answer with its time relative to the Rust solver, then the stderr of any
+/// external run that failed.
+pub fn report(comparisons: &[DayComparison]) -> String {
+    let mut report = String::new();
+    row(&mut report, ["Day", "Part", "Solver", "Answer", "Time", "Relative", "Result"]);
+
+    for comparison in comparisons {
+        for part in &comparison.parts {
+            let rust_time = format!("{:.2?}", part.rust.elapsed);
+            row(
+                &mut report,
+                [
+                    &comparison.day.to_string(),
+                    &part.part.to_string(),
+                    RUST,
+                    &describe_answer(&part.rust.answer),
+                    &rust_time,
+                    "1.0x",
+                    "",
+                ],
+            );
+
+            for external in &part.external {
+                let relative = match external.answer.is_err() || part.rust.elapsed.is_zero() {
+                    true => "-".to_string(),
+                    false => format!("{:.1}x", external.elapsed.as_secs_f64() / part.rust.elapsed.as_secs_f64()),
+                };
+                let result = match part.agreement(external) {
+                    Agreement::Agrees => "ok",
+                    Agreement::Mismatch => "MISMATCH",
+                    Agreement::Failed => "FAILED",
+                    Agreement::Unverified => "unverified",
+                };
+                let time = format!("{:.2?}", external.elapsed);
+
+                row(
+                    &mut report,
+                    ["", "", &external.solver, &describe_answer(&external.answer), &time, &relative, result],
+                );
+            }
+        }
+    }
+
+    for comparison in comparisons {
+        for (solver, stderr) in &comparison.stderr {
+            let _ = writeln!(report, "\nDay {} {} stderr:", comparison.day, solver);
+            for line in stderr.trim_end().lines() {
+                let _ = writeln!(report, "  {}", line);
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use std::path::PathBuf;
+
+    const INPUT: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    /// Writes each `(name, script)` as a shell script alongside the example input.
+    fn scripts(test: &str, scripts: &[(&str, &str)]) -> (PathBuf, Vec<ExternalSolver>) {
+        let dir = std::env::temp_dir().join(format!("aoc-compare-{}-{}", test, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input.txt"), INPUT).unwrap();
+
+        let solvers = scripts
+            .iter()
+            .map(|(name, script)| {
+                let path = dir.join(format!("{}.sh", name));
+                fs::write(&path, script).unwrap();
+                ExternalSolver {
+                    name: name.to_string(),
+                    command: vec!["sh".to_string(), path.to_string_lossy().into_owned(), "{input}".to_string()],
+                }
+            })
+            .collect();
+
+        (dir, solvers)
+    }
+
+    #[test]
+    fn should_parse_solvers_and_fill_in_their_commands() {
+        // Given
+        let config = "# comment\n\npython: python3 py/day_{day:02}.py --year={year} {input}\ngo: ./go/aoc {day}\n";
+
+        // When
+        let solvers = SolverConfig::from_str(config).unwrap().solvers;
+        let arguments = solvers[0].arguments(2024, 6, Path::new("/inputs/06.txt"));
+
+        // Then
+        assert_eq!(vec!["python", "go"], solvers.iter().map(|x| x.name.as_str()).collect::<Vec<_>>());
+        assert_eq!(vec!["python3", "py/day_06.py", "--year=2024", "/inputs/06.txt"], arguments);
+        assert_eq!(vec!["./go/aoc", "6"], solvers[1].arguments(2024, 6, Path::new("x")));
+        assert!(SolverConfig::from_str("go: a\ngo: b").is_err());
+        assert!(SolverConfig::from_str("go:").is_err());
+    }
+
+    #[test]
+    fn should_report_matches_and_mismatches() {
+        // Given
+        let (dir, solvers) = scripts(
+            "answers",
+            &[
+                ("same", "read -r line < \"$1\"\necho 'part_1: 11'\necho 'part_2: 31'\n"),
+                ("wrong", "echo 'part_1: 11'\necho 'part_2: 30'\n"),
+            ],
+        );
+        let day = registry::day(2024, 1).unwrap();
+
+        // When
+        let comparison = compare_day(day, INPUT, &dir.join("input.txt"), &solvers, Duration::from_secs(10)).unwrap();
+
+        // Then
+        let part_2 = &comparison.parts[1];
+        assert_eq!(Agreement::Agrees, comparison.parts[0].agreement(&comparison.parts[0].external[1]));
+        assert_eq!(Agreement::Agrees, part_2.agreement(&part_2.external[0]));
+        assert_eq!(Agreement::Mismatch, part_2.agreement(&part_2.external[1]));
+        assert_eq!(1, comparison.problems());
+        assert!(report(&[comparison]).contains("MISMATCH"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn should_time_out_and_capture_stderr_of_failed_solvers() {
+        // Given
+        let (dir, solvers) = scripts(
+            "failures",
+            &[
+                ("slow", "echo 'thinking' >&2\nsleep 10\necho 'part_1: 11'\n"),
+                ("broken", "echo 'part_1: 11'\necho 'index out of range' >&2\nexit 3\n"),
+            ],
+        );
+        let input = dir.join("input.txt");
+
+        // When
+        let slow = solvers[0].run(2024, 1, &input, Duration::from_millis(200));
+        let broken = solvers[1].run(2024, 1, &input, Duration::from_secs(10));
+
+        // Then
+        assert!(matches!(slow.outcome, ExternalOutcome::TimedOut));
+        assert!(slow.elapsed < Duration::from_secs(5));
+        assert_eq!("thinking\n", slow.stderr);
+        assert!(matches!(broken.outcome, ExternalOutcome::Failed(ref x) if x == "exit status: 3"));
+        assert_eq!("index out of range\n", broken.stderr);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+/// Escapes text to fit on one field of a tab separated line, backslashes first so that each
+/// escape reads back unambiguously.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Reverses [`escape`] in one pass from the left. A backslash before anything else is kept.
+pub fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('\\')) => unescaped.push('\\'),
+            ('\\', Some('n')) => unescaped.push('\n'),
+            ('\\', Some('t')) => unescaped.push('\t'),
+            (c, _) => {
+                unescaped.push(c);
+                continue;
+            }
+        }
+        chars.next();
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_backslashes_before_n() {
+        // Given
+        let texts = ["a\nb", "a\\nb", "\\\n\\\\n\t", "C:\\new\\table"];
+
+        // When
+        let escaped = texts.map(escape);
+
+        // Then
+        assert_eq!("a\\\\nb", escaped[1]);
+        assert!(escaped.iter().all(|x| !x.contains(['\n', '\t'])));
+        assert_eq!(texts.map(String::from), escaped.map(|x| unescape(&x)));
+    }
+}
@@ -0,0 +1,264 @@
+/// Just enough of an HTML parser to read Advent of Code puzzle pages.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+const VOID_ELEMENTS: [&str; 8] = ["br", "hr", "img", "input", "link", "meta", "area", "source"];
+const RAW_TEXT_ELEMENTS: [&str; 2] = ["script", "style"];
+
+impl Element {
+    fn new(name: &str, attributes: Vec<(String, String)>) -> Element {
+        Element {
+            name: name.to_string(),
+            attributes,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(x, _)| x == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn has_class(&self, class: &str) -> bool {
+        self.attribute("class")
+            .is_some_and(|x| x.split_whitespace().any(|x| x == class))
+    }
+
+    /// Every descendant element named `name`, in document order.
+    pub fn find_all<'a>(&'a self, name: &str) -> Vec<&'a Element> {
+        let mut found = Vec::new();
+        for child in &self.children {
+            if let Node::Element(element) = child {
+                if element.name == name {
+                    found.push(element);
+                }
+                found.append(&mut element.find_all(name));
+            }
+        }
+
+        found
+    }
+
+    pub fn text(&self) -> String {
+        self.children
+            .iter()
+            .map(|child| match child {
+                Node::Element(element) => element.text(),
+                Node::Text(text) => text.clone(),
+            })
+            .collect()
+    }
+}
+
+pub fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..].find(';').filter(|x| *x <= 10).map(|end| &rest[1..end + 1]);
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => match entity.strip_prefix("#x").or_else(|| entity.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                None => entity
+                    .strip_prefix('#')
+                    .and_then(|x| x.parse().ok())
+                    .and_then(char::from_u32),
+            },
+        });
+
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn parse_attributes(text: &str) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    let mut rest = text.trim();
+
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c == '=' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(after) => {
+                let after = after.trim_start();
+                let (value, remaining) = match after.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let end = after[1..].find(quote).map(|x| x + 1).unwrap_or(after.len());
+                        (&after[1..end], after.get(end + 1..).unwrap_or(""))
+                    }
+                    _ => {
+                        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                        (&after[..end], &after[end..])
+                    }
+                };
+                rest = remaining.trim_start();
+                decode_entities(value)
+            }
+            None => String::new(),
+        };
+
+        if !name.is_empty() {
+            attributes.push((name, value));
+        }
+    }
+
+    attributes
+}
+
+/// Parses `html` into a tree under a synthetic root element. Unclosed and mismatched tags are
+/// closed as late as possible rather than rejected.
+pub fn parse(html: &str) -> Element {
+    let mut stack = vec![Element::new("#root", Vec::new())];
+    let mut rest = html;
+
+    fn close(stack: &mut Vec<Element>) {
+        if stack.len() > 1 {
+            let element = stack.pop().expect("Stack should not be empty");
+            stack
+                .last_mut()
+                .expect("Root should remain")
+                .children
+                .push(Node::Element(element));
+        }
+    }
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            stack.last_mut().unwrap().children.push(Node::Text(decode_entities(rest)));
+            break;
+        };
+
+        if start > 0 {
+            stack.last_mut().unwrap().children.push(Node::Text(decode_entities(&rest[..start])));
+        }
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map(|x| &comment[x + 3..]).unwrap_or("");
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            stack.last_mut().unwrap().children.push(Node::Text(decode_entities(rest)));
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_ascii_lowercase();
+            if let Some(depth) = stack.iter().rposition(|x| x.name == name) {
+                while stack.len() > depth.max(1) {
+                    close(&mut stack);
+                }
+            }
+            continue;
+        }
+
+        let self_closing = tag.ends_with('/');
+        let tag = tag.trim_end_matches('/');
+        let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        let name = tag[..name_end].to_ascii_lowercase();
+        let element = Element::new(&name, parse_attributes(&tag[name_end..]));
+
+        if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+            let closing = format!("</{}", name);
+            let end = rest.to_ascii_lowercase().find(&closing).unwrap_or(rest.len());
+            rest = rest[end..].find('>').map(|x| &rest[end + x + 1..]).unwrap_or("");
+            stack.last_mut().unwrap().children.push(Node::Element(element));
+        } else if self_closing || VOID_ELEMENTS.contains(&name.as_str()) {
+            stack.last_mut().unwrap().children.push(Node::Element(element));
+        } else {
+            stack.push(element);
+        }
+    }
+
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+
+    stack.pop().expect("Root should remain")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_decode_entities() {
+        // When
+        let decoded = decode_entities("a &lt;b&gt; &amp; &#39;c&#x27; &unknown; &");
+
+        // Then
+        assert_eq!("a <b> & 'c' &unknown; &", decoded);
+    }
+
+    #[test]
+    fn should_parse_nested_elements_and_attributes() {
+        // Given
+        let html = r#"<!DOCTYPE html><main><article class="day-desc"><p>Try <a href="/2024/day/1/input">this</a><br/>now</p></article><script>if (a < b) {}</script></main>"#;
+
+        // When
+        let root = parse(html);
+
+        // Then
+        let articles = root.find_all("article");
+        assert_eq!(1, articles.len());
+        assert!(articles[0].has_class("day-desc"));
+        assert_eq!(Some("/2024/day/1/input"), articles[0].find_all("a")[0].attribute("href"));
+        assert_eq!("Try thisnow", articles[0].text());
+    }
+
+    #[test]
+    fn should_close_unclosed_elements() {
+        // Given
+        let html = "<ul><li>one<li>two</ul><p>after";
+
+        // When
+        let root = parse(html);
+
+        // Then
+        assert_eq!("onetwoafter", root.text());
+        assert_eq!(1, root.find_all("p").len());
+    }
+}
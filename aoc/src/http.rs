@@ -0,0 +1,39 @@
+use crate::workspace::Workspace;
+use eyre::{eyre, WrapErr};
+use std::env;
+use std::fs;
+
+const USER_AGENT: &str = "github.com/gutsdream/aoc-2024 runner";
+
+/// Reads the Advent of Code session cookie from `AOC_SESSION`, falling back to `.session` in the
+/// workspace root.
+pub fn session(workspace: &Workspace) -> eyre::Result<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let path = workspace.root().join(".session");
+    fs::read_to_string(&path)
+        .map(|x| x.trim().to_string())
+        .wrap_err_with(|| format!("Set AOC_SESSION or write the session cookie to {}", path.display()))
+}
+
+pub fn get(url: &str, session: &str) -> eyre::Result<String> {
+    ureq::get(url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|e| eyre!("GET {} failed: {}", url, e))?
+        .into_string()
+        .wrap_err_with(|| format!("Could not read response from {}", url))
+}
+
+pub fn post_form(url: &str, session: &str, fields: &[(&str, &str)]) -> eyre::Result<String> {
+    ureq::post(url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .send_form(fields)
+        .map_err(|e| eyre!("POST {} failed: {}", url, e))?
+        .into_string()
+        .wrap_err_with(|| format!("Could not read response from {}", url))
+}
@@ -0,0 +1,388 @@
+use eyre::{eyre, WrapErr};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+    pub star_index: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+/// A private leaderboard as exported by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    fn star(&self, day: u8, part: u8) -> Option<&Star> {
+        self.completion_day_level.get(&day)?.get(&part)
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DayCompletion {
+    pub day: u8,
+    pub part_1_secs: Option<i64>,
+    pub part_2_secs: Option<i64>,
+    /// Time between getting the first and second star.
+    pub delta_secs: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MemberReport {
+    pub id: u64,
+    pub name: String,
+    pub stars: u32,
+    pub local_score: u64,
+    pub recomputed_score: u64,
+    pub days: Vec<DayCompletion>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct Standing {
+    pub id: u64,
+    pub rank: usize,
+    pub score: u64,
+    /// Positive when the member climbed since the previous day.
+    pub change: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayStandings {
+    pub day: u8,
+    pub standings: Vec<Standing>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub event: String,
+    pub members: Vec<MemberReport>,
+    pub standings: Vec<DayStandings>,
+}
+
+/// Puzzles unlock at midnight US Eastern time, which is 05:00 UTC in December.
+pub fn unlock_ts(year: i64, day: u8) -> i64 {
+    // Days from 1970-01-01 to `year`-12-`day`, using Howard Hinnant's days_from_civil.
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (12 - 3) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    days * 86400 + 5 * 3600
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> eyre::Result<Leaderboard> {
+        serde_json::from_str(json).wrap_err("Could not parse leaderboard JSON")
+    }
+
+    fn year(&self) -> eyre::Result<i64> {
+        self.event
+            .parse()
+            .map_err(|_| eyre!("Leaderboard event {:?} is not a year", self.event))
+    }
+
+    fn days(&self) -> Vec<u8> {
+        let mut days = self
+            .members
+            .values()
+            .flat_map(|x| x.completion_day_level.keys().copied())
+            .collect::<Vec<_>>();
+        days.sort();
+        days.dedup();
+
+        days
+    }
+
+    /// Awards each star `members - position` points, where position is the 0-based order in
+    /// which members got it, as the site does for local scores.
+    fn points(&self, day: u8, part: u8) -> HashMap<u64, u64> {
+        let mut finishers = self
+            .members
+            .values()
+            .filter_map(|x| x.star(day, part).map(|star| (star.get_star_ts, star.star_index, x.id)))
+            .collect::<Vec<_>>();
+        finishers.sort();
+
+        let member_count = self.members.len() as u64;
+        finishers
+            .into_iter()
+            .enumerate()
+            .map(|(position, (_, _, id))| (id, member_count - position as u64))
+            .collect()
+    }
+
+    fn standings(&self, points: &BTreeMap<u8, [HashMap<u64, u64>; 2]>, day: u8) -> Vec<(u64, u64)> {
+        let mut scores = self
+            .members
+            .values()
+            .map(|member| {
+                let score = points
+                    .range(..=day)
+                    .flat_map(|(_, parts)| parts.iter())
+                    .filter_map(|x| x.get(&member.id))
+                    .sum::<u64>();
+                let last_star = member
+                    .completion_day_level
+                    .range(..=day)
+                    .flat_map(|(_, parts)| parts.values())
+                    .map(|x| x.get_star_ts)
+                    .max()
+                    .unwrap_or(i64::MAX);
+
+                (Reverse(score), last_star, member.id)
+            })
+            .collect::<Vec<_>>();
+        scores.sort();
+
+        scores
+            .into_iter()
+            .map(|(Reverse(score), _, id)| (id, score))
+            .collect()
+    }
+
+    pub fn report(&self) -> eyre::Result<Report> {
+        let year = self.year()?;
+        let days = self.days();
+        let points = days
+            .iter()
+            .map(|&day| (day, [self.points(day, 1), self.points(day, 2)]))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut members = self
+            .members
+            .values()
+            .map(|member| {
+                let days = member
+                    .completion_day_level
+                    .keys()
+                    .map(|&day| {
+                        let unlock = unlock_ts(year, day);
+                        let part_1 = member.star(day, 1).map(|x| x.get_star_ts);
+                        let part_2 = member.star(day, 2).map(|x| x.get_star_ts);
+
+                        DayCompletion {
+                            day,
+                            part_1_secs: part_1.map(|x| x - unlock),
+                            part_2_secs: part_2.map(|x| x - unlock),
+                            delta_secs: part_1.zip(part_2).map(|(a, b)| b - a),
+                        }
+                    })
+                    .collect();
+
+                MemberReport {
+                    id: member.id,
+                    name: member.display_name(),
+                    stars: member.stars,
+                    local_score: member.local_score,
+                    recomputed_score: points.values().flatten().filter_map(|x| x.get(&member.id)).sum(),
+                    days,
+                }
+            })
+            .collect::<Vec<_>>();
+        let final_order = self.standings(&points, u8::MAX);
+        members.sort_by_key(|x| final_order.iter().position(|(id, _)| *id == x.id));
+
+        let mut previous_ranks = HashMap::new();
+        let standings = days
+            .iter()
+            .map(|&day| {
+                let standings = self
+                    .standings(&points, day)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (id, score))| {
+                        let rank = i + 1;
+                        let change = previous_ranks
+                            .insert(id, rank)
+                            .map(|previous| previous as i64 - rank as i64)
+                            .unwrap_or(0);
+
+                        Standing { id, rank, score, change }
+                    })
+                    .collect();
+
+                DayStandings { day, standings }
+            })
+            .collect();
+
+        Ok(Report {
+            event: self.event.clone(),
+            members,
+            standings,
+        })
+    }
+}
+
+fn duration(secs: Option<i64>) -> String {
+    match secs {
+        Some(secs) => format!("{}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60),
+        None => "-".to_string(),
+    }
+}
+
+impl Report {
+    fn name(&self, id: u64) -> &str {
+        self.members
+            .iter()
+            .find(|x| x.id == id)
+            .map(|x| x.name.as_str())
+            .unwrap_or("?")
+    }
+
+    pub fn table(&self) -> String {
+        let width = self.members.iter().map(|x| x.name.len()).max().unwrap_or(4).max(4);
+        let mut table = String::new();
+
+        let _ = writeln!(table, "Advent of Code {}", self.event);
+        let _ = writeln!(table, "{:>4}  {:<width$}  {:>5}  {:>5}  {:>10}", "Rank", "Name", "Stars", "Score", "Recomputed");
+        for (i, member) in self.members.iter().enumerate() {
+            let _ = writeln!(
+                table,
+                "{:>4}  {:<width$}  {:>5}  {:>5}  {:>10}",
+                i + 1,
+                member.name,
+                member.stars,
+                member.local_score,
+                member.recomputed_score
+            );
+        }
+
+        for standings in &self.standings {
+            let _ = writeln!(table, "\nDay {}", standings.day);
+            let _ = writeln!(table, "{:>4}  {:<width$}  {:>6}  {:>9}  {:>9}  {:>9}", "Rank", "Name", "Change", "Part 1", "Part 2", "Delta");
+
+            for standing in &standings.standings {
+                let completion = self
+                    .members
+                    .iter()
+                    .find(|x| x.id == standing.id)
+                    .and_then(|x| x.days.iter().find(|x| x.day == standings.day));
+                let change = match standing.change {
+                    0 => "=".to_string(),
+                    change => format!("{:+}", change),
+                };
+
+                let _ = writeln!(
+                    table,
+                    "{:>4}  {:<width$}  {:>6}  {:>9}  {:>9}  {:>9}",
+                    standing.rank,
+                    self.name(standing.id),
+                    change,
+                    duration(completion.and_then(|x| x.part_1_secs)),
+                    duration(completion.and_then(|x| x.part_2_secs)),
+                    duration(completion.and_then(|x| x.delta_secs)),
+                );
+            }
+        }
+
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const FIXTURE: &str = include_str!("../fixtures/leaderboard.json");
+
+    #[test]
+    fn should_compute_unlock_time() {
+        // When
+        let unlock = unlock_ts(2024, 1);
+
+        // Then
+        assert_eq!(1733029200, unlock);
+    }
+
+    #[test]
+    fn should_compute_completion_times_and_deltas() {
+        // Given
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+
+        // When
+        let report = leaderboard.report().unwrap();
+
+        // Then
+        let alice = report.members.iter().find(|x| x.name == "alice").unwrap();
+        assert_eq!(
+            DayCompletion {
+                day: 1,
+                part_1_secs: Some(180),
+                part_2_secs: Some(300),
+                delta_secs: Some(120),
+            },
+            alice.days[0]
+        );
+    }
+
+    #[test]
+    fn should_recompute_local_scores() {
+        // Given
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+
+        // When
+        let report = leaderboard.report().unwrap();
+
+        // Then
+        let scores = report
+            .members
+            .iter()
+            .map(|x| (x.name.as_str(), x.local_score, x.recomputed_score))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![("bob", 8, 8), ("alice", 8, 8), ("(anonymous user #1003)", 6, 6)],
+            scores
+        );
+    }
+
+    #[test]
+    fn should_track_rank_changes_between_days() {
+        // Given
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+
+        // When
+        let report = leaderboard.report().unwrap();
+
+        // Then
+        assert_eq!(
+            vec![
+                Standing { id: 1002, rank: 1, score: 8, change: 1 },
+                Standing { id: 1001, rank: 2, score: 8, change: -1 },
+                Standing { id: 1003, rank: 3, score: 6, change: 0 },
+            ],
+            report.standings[1].standings
+        );
+    }
+
+    #[test]
+    fn should_render_table() {
+        // Given
+        let report = Leaderboard::parse(FIXTURE).unwrap().report().unwrap();
+
+        // When
+        let table = report.table();
+
+        // Then
+        assert!(table.contains("   1  bob                         +1    0:06:40          -          -"));
+    }
+}
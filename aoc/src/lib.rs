@@ -0,0 +1,17 @@
+pub mod answer_book;
+pub mod budget;
+pub mod cache;
+pub mod compare;
+pub mod escape;
+pub mod html;
+pub mod http;
+pub mod leaderboard;
+pub mod puzzle;
+pub mod registry;
+pub mod repl;
+pub mod schedule;
+pub mod serve;
+pub mod status;
+pub mod submit;
+pub mod watch;
+pub mod workspace;
@@ -0,0 +1,674 @@
+use aoc::answer_book::AnswerBook;
+use aoc::cache::{self, AnswerCache, CachedRun};
+use aoc::compare::{self, SolverConfig};
+use aoc::leaderboard::Leaderboard;
+use aoc::puzzle::{self, DEFAULT_BASE_URL};
+use aoc::status::{self, Progress};
+use aoc::submit::{self, Outcome};
+use aoc::registry::{Arguments, Day, Variant};
+use aoc::repl::{self, Repl, Script};
+use aoc::schedule::{self, Job, JobOutcome};
+use aoc::{http, registry};
+use aoc::serve::{self, ServeConfig};
+use aoc::watch;
+use aoc::workspace::Workspace;
+use clap::{Parser, Subcommand};
+use eyre::{eyre, WrapErr};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use vault::{Key, State};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    /// Workspace root containing the YYYY/day_NN crates
+    #[arg(long, global = true)]
+    root: Option<PathBuf>,
+
+    /// The event whose days commands work on
+    #[arg(long, global = true, default_value_t = registry::LATEST_YEAR)]
+    year: u16,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a day's real input
+    Run {
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Overrides a day parameter, e.g. `--param width=11`
+        #[arg(long = "param", value_parser = parse_param)]
+        params: Vec<(String, i64)>,
+        /// Prints tab separated results for other tools to read
+        #[arg(long)]
+        porcelain: bool,
+        /// Solves every part even if its answer is cached
+        #[arg(long)]
+        no_cache: bool,
+        /// Solves with a named variant instead of the part's first one
+        #[arg(long, conflicts_with = "all_variants")]
+        variant: Option<String>,
+        /// Solves with every variant and fails unless they all give the same answer
+        #[arg(long, conflicts_with = "porcelain")]
+        all_variants: bool,
+    },
+    /// Solves every registered day that has an input, several parts at a time
+    All {
+        /// Solves every part even if its answer is cached
+        #[arg(long)]
+        no_cache: bool,
+        /// How long a part may run before it is cancelled and reported as timed out
+        #[arg(long, default_value_t = 10_000)]
+        timeout_ms: u64,
+        /// How many parts to solve at once, by default one per CPU
+        #[arg(long)]
+        jobs: Option<usize>,
+    },
+    /// Runs the external solvers in solvers.txt on each day's input and compares their answers
+    /// and times with the Rust solvers
+    Compare {
+        /// Compares only this day
+        day: Option<u8>,
+        /// A solver list to use instead of solvers.txt
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// How long an external solver may run before it is killed
+        #[arg(long, default_value_t = 30_000)]
+        timeout_ms: u64,
+    },
+    /// Parses a day's input once and answers queries about it, such as day 06's guard path with
+    /// an extra obstruction
+    Repl {
+        day: u8,
+        #[arg(long = "param", value_parser = parse_param)]
+        params: Vec<(String, i64)>,
+        /// Runs a saved session instead of reading from the terminal, failing if any command's
+        /// output differs from the script's
+        #[arg(long)]
+        script: Option<PathBuf>,
+    },
+    /// Re-runs a day's example tests and real input whenever its files change
+    Watch {
+        day: u8,
+        /// How long to wait for saves to settle before re-running
+        #[arg(long, default_value_t = 200)]
+        debounce_ms: u64,
+    },
+    /// Serves every registered day over HTTP
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:8024")]
+        address: String,
+        /// Largest accepted puzzle input
+        #[arg(long, default_value_t = 1024 * 1024)]
+        max_body_bytes: usize,
+        /// How long a single solve may take before the request fails
+        #[arg(long, default_value_t = 10_000)]
+        timeout_ms: u64,
+        /// How many requests to handle and solvers to run at once, by default one per CPU
+        #[arg(long)]
+        jobs: Option<usize>,
+    },
+    /// Reports completion times and standings from a private leaderboard export
+    Leaderboard {
+        /// A saved copy of the leaderboard JSON
+        #[arg(long, conflicts_with = "url", required_unless_present = "url")]
+        file: Option<PathBuf>,
+        /// e.g. https://adventofcode.com/2024/leaderboard/private/view/<id>.json
+        #[arg(long)]
+        url: Option<String>,
+        #[arg(long)]
+        json: bool,
+    },
+    /// Saves a day's puzzle description as PUZZLE.md and its examples as fixtures
+    Puzzle {
+        day: u8,
+        #[arg(long, default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Prints a calendar of which days and parts are solved
+    Status {
+        /// Fails if a part recorded in progress.txt is no longer solved
+        #[arg(long)]
+        check: bool,
+        /// Records the currently solved parts in progress.txt
+        #[arg(long)]
+        record: bool,
+    },
+    /// Solves a part and submits its answer, recording it in the answer book if accepted
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(long = "param", value_parser = parse_param)]
+        params: Vec<(String, i64)>,
+        #[arg(long, default_value = DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Encrypts and decrypts every year's inputs and answer books with the key in .vault-key
+    Vault {
+        #[command(subcommand)]
+        action: VaultAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum VaultAction {
+    /// Writes a new key to .vault-key at the workspace root
+    Keygen,
+    /// Lists which inputs and answer books are encrypted and up to date
+    Status,
+    /// Encrypts every changed input and answer book into its .vault file
+    Encrypt,
+    /// Decrypts every .vault file that has no plaintext copy
+    Decrypt {
+        /// Overwrites plaintext that differs from its vault file
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+fn parse_param(param: &str) -> Result<(String, i64), String> {
+    let (name, value) = param
+        .split_once('=')
+        .ok_or_else(|| format!("{:?} should be name=value", param))?;
+    let value = value
+        .parse()
+        .map_err(|_| format!("{:?} is not an integer", value))?;
+
+    Ok((name.to_string(), value))
+}
+
+/// Solves a part through the answer cache, unless caching is turned off.
+fn solve_part(
+    cache: &mut Option<AnswerCache>,
+    registered: &Day,
+    part: u8,
+    variant: Option<&Variant>,
+    input: &str,
+    arguments: &Arguments,
+    fingerprint: &str,
+) -> CachedRun {
+    match (cache, variant) {
+        (Some(cache), Some(variant)) => cache.solve(registered, part, variant, input, arguments, fingerprint),
+        (None, Some(variant)) => CachedRun {
+            run: registered.solve_with(part, variant, input, arguments),
+            cached: false,
+        },
+        (_, None) => CachedRun {
+            run: registered.solve(part, input, arguments),
+            cached: false,
+        },
+    }
+}
+
+fn describe_run(result: &CachedRun) -> String {
+    let cached = if result.cached { ", cached" } else { "" };
+    match &result.run.answer {
+        Ok(answer) => format!("{} ({:.2?}{})", answer, result.run.elapsed, cached),
+        Err(e) => format!("error: {} ({:.2?})", e, result.run.elapsed),
+    }
+}
+
+fn load_cache(workspace: &Workspace, no_cache: bool) -> eyre::Result<Option<AnswerCache>> {
+    match no_cache {
+        true => Ok(None),
+        false => AnswerCache::load(&workspace.cache_path()).map(Some),
+    }
+}
+
+fn save_cache(workspace: &Workspace, cache: Option<AnswerCache>, porcelain: bool) -> eyre::Result<()> {
+    if let Some(cache) = cache {
+        cache.save(&workspace.cache_path())?;
+        if !porcelain {
+            println!("{}", cache.stats());
+        }
+    }
+
+    Ok(())
+}
+
+/// Which variants to solve each part with.
+enum VariantChoice {
+    First,
+    Named(String),
+    All,
+}
+
+struct RunOptions {
+    porcelain: bool,
+    no_cache: bool,
+    variants: VariantChoice,
+}
+
+fn run(
+    workspace: &Workspace,
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    params: &[(String, i64)],
+    options: RunOptions,
+) -> eyre::Result<()> {
+    let registered = registry::day(year, day).ok_or_else(|| eyre!("Day {} of {} is not registered", day, year))?;
+    let arguments = registered.arguments(params)?;
+    let input = workspace.read_input(year, day)?;
+    // Comparing variants needs them all actually solved.
+    let no_cache = options.no_cache || matches!(options.variants, VariantChoice::All);
+    let mut cache = load_cache(workspace, no_cache)?;
+    let fingerprint = cache::fingerprint(workspace, year, day)?;
+
+    let parts = match (part, &options.variants) {
+        (Some(part), _) => vec![part],
+        // Without a part, a named variant picks out the parts that have it.
+        (None, VariantChoice::Named(name)) => {
+            let parts = (1..=2)
+                .filter(|x| registered.variant(*x, name).is_ok())
+                .collect::<Vec<_>>();
+            if parts.is_empty() {
+                return Err(eyre!("Day {} has no variant {:?}", day, name));
+            }
+            parts
+        }
+        (None, _) => vec![1, 2],
+    };
+
+    let mut disagreements = Vec::new();
+    for part in parts {
+        let variants = match &options.variants {
+            VariantChoice::First => registered.part(part).variants().into_iter().take(1).collect(),
+            VariantChoice::Named(name) => vec![registered.variant(part, name)?],
+            VariantChoice::All => registered.part(part).variants(),
+        };
+        let labelled = !matches!(options.variants, VariantChoice::First);
+
+        let mut answers = Vec::new();
+        for variant in variants.iter().map(Some).chain(variants.is_empty().then_some(None)) {
+            let result = solve_part(&mut cache, registered, part, variant, &input, &arguments, &fingerprint);
+            let label = match variant.filter(|_| labelled) {
+                Some(variant) => format!("Part {} [{}]", part, variant.name),
+                None => format!("Part {}", part),
+            };
+
+            match options.porcelain {
+                true => println!("{}", watch::porcelain(&result.run)),
+                false => println!("{}: {}", label, describe_run(&result)),
+            }
+            answers.push(result.run.answer.map_err(|e| e.to_string()));
+        }
+
+        let compared = answers.len() > 1;
+        if compared && (answers.windows(2).any(|x| x[0] != x[1]) || answers.iter().any(|x| x.is_err())) {
+            disagreements.push(part);
+        }
+    }
+
+    save_cache(workspace, cache, options.porcelain)?;
+
+    match disagreements.is_empty() {
+        true => Ok(()),
+        false => Err(eyre!("Variants of day {} disagree on part {:?}", day, disagreements)),
+    }
+}
+
+struct AllOptions {
+    no_cache: bool,
+    timeout: Duration,
+    jobs: usize,
+}
+
+fn run_all(workspace: &Workspace, year: u16, options: AllOptions) -> eyre::Result<()> {
+    let days = registry::days(year);
+    if days.is_empty() {
+        return Err(eyre!("Year {} has no registered days", year));
+    }
+    let mut cache = load_cache(workspace, options.no_cache)?;
+    // Lines are keyed by day and part, with part 0 for a skipped day, and printed in order.
+    let mut lines = BTreeMap::new();
+    let mut jobs = Vec::new();
+    let mut keys = Vec::new();
+
+    for registered in days {
+        let input = match workspace.read_input(year, registered.day) {
+            Ok(input) => Arc::new(input),
+            Err(e) => {
+                lines.insert((registered.day, 0), format!("Day {:>2}: skipped, {}", registered.day, e));
+                continue;
+            }
+        };
+        let arguments = registered.arguments(&[])?;
+        let fingerprint = cache::fingerprint(workspace, year, registered.day)?;
+
+        for part in 1..=2 {
+            let Some(variant) = registered.part(part).variants().first().copied() else {
+                let run = registered.solve(part, &input, &arguments);
+                lines.insert((registered.day, part), describe_run(&CachedRun { run, cached: false }));
+                continue;
+            };
+
+            let key = AnswerCache::key(registered, part, &variant, &input, &arguments, &fingerprint);
+            if let Some(run) = cache.as_mut().and_then(|x| x.lookup(&key)) {
+                lines.insert((registered.day, part), describe_run(&CachedRun { run, cached: true }));
+                continue;
+            }
+
+            keys.push(key);
+            jobs.push(Job {
+                day: registered,
+                part,
+                variant,
+                input: input.clone(),
+                arguments: arguments.clone(),
+            });
+        }
+    }
+
+    let labels = jobs.iter().map(|x| (x.day.day, x.part)).collect::<Vec<_>>();
+    let outcomes = schedule::solve_all(jobs, options.jobs, options.timeout);
+    for ((label, key), outcome) in labels.into_iter().zip(keys).zip(outcomes) {
+        let line = match outcome {
+            JobOutcome::Finished(run) => {
+                if let Some(cache) = &mut cache {
+                    cache.record(key, &run);
+                }
+                describe_run(&CachedRun { run, cached: false })
+            }
+            JobOutcome::TimedOut(timeout) => format!("timed out after {:.2?}", timeout),
+        };
+        lines.insert(label, line);
+    }
+
+    for ((day, part), line) in lines {
+        match part {
+            0 => println!("{}", line),
+            _ => println!("Day {:>2} part {}: {}", day, part, line),
+        }
+    }
+
+    save_cache(workspace, cache, false)
+}
+
+fn compare_solvers(
+    workspace: &Workspace,
+    year: u16,
+    day: Option<u8>,
+    config: Option<PathBuf>,
+    timeout: Duration,
+) -> eyre::Result<()> {
+    let solvers = SolverConfig::load(&config.unwrap_or_else(|| workspace.solvers_path()))?.solvers;
+    let days = registry::days(year)
+        .iter()
+        .filter(|x| day.is_none_or(|day| x.day == day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err(eyre!("No registered days to compare in {}", year));
+    }
+
+    let mut comparisons = Vec::new();
+    for registered in days {
+        let input = match workspace.read_input(year, registered.day) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {:>2}: skipped, {}", registered.day, e);
+                continue;
+            }
+        };
+
+        // External solvers need a file to read, so an input only kept in the vault is written
+        // out under target/ for them.
+        let mut input_path = workspace.input_path(year, registered.day);
+        if !input_path.exists() {
+            let dir = workspace.root().join("target").join("compare");
+            fs::create_dir_all(&dir)?;
+            input_path = dir.join(format!("{}_day_{:02}.txt", year, registered.day));
+            fs::write(&input_path, &*input)?;
+        }
+
+        comparisons.push(compare::compare_day(registered, &input, &input_path, &solvers, timeout)?);
+    }
+
+    print!("{}", compare::report(&comparisons));
+
+    let problems = comparisons.iter().map(|x| x.problems()).sum::<usize>();
+    match problems {
+        0 => Ok(()),
+        _ => Err(eyre!("{} external answers were wrong or missing", problems)),
+    }
+}
+
+fn repl(workspace: &Workspace, year: u16, day: u8, params: &[(String, i64)], script: Option<PathBuf>) -> eyre::Result<()> {
+    let registered = registry::day(year, day).ok_or_else(|| eyre!("Day {} of {} is not registered", day, year))?;
+    let explorer = repl::explorer(year, day).ok_or_else(|| eyre!("Day {} of {} has no REPL commands", day, year))?;
+    let arguments = registered.arguments(params)?;
+    let input = workspace.read_input(year, day)?;
+    let mut session = Repl::new((explorer.load)(&parsing::normalise(&input), &arguments)?);
+
+    let Some(script) = script else {
+        return repl::interact(&mut session, &format!("day {:02}> ", day), &workspace.repl_history_path());
+    };
+
+    let steps = fs::read_to_string(&script)
+        .wrap_err_with(|| format!("Could not read {}", script.display()))?
+        .parse::<Script>()?;
+    let mismatches = repl::run_script(&mut session, &steps, &mut std::io::stdout())?;
+
+    match mismatches.is_empty() {
+        true => Ok(()),
+        false => Err(eyre!(
+            "{} of {} commands in {} gave different output",
+            mismatches.len(),
+            steps.steps.len(),
+            script.display()
+        )),
+    }
+}
+
+fn leaderboard(workspace: &Workspace, file: Option<PathBuf>, url: Option<String>, json: bool) -> eyre::Result<()> {
+    let export = match (file, url) {
+        (Some(file), _) => fs::read_to_string(&file).wrap_err_with(|| format!("Could not read {}", file.display()))?,
+        (None, Some(url)) => http::get(&url, &http::session(workspace)?)?,
+        (None, None) => return Err(eyre!("Either --file or --url is required")),
+    };
+    let report = Leaderboard::parse(&export)?.report()?;
+
+    match json {
+        true => println!("{}", serde_json::to_string_pretty(&report)?),
+        false => print!("{}", report.table()),
+    }
+
+    Ok(())
+}
+
+fn fetch_puzzle(workspace: &Workspace, year: u16, day: u8, base_url: &str) -> eyre::Result<()> {
+    let puzzle = puzzle::fetch(base_url, year, day, &http::session(workspace)?)?;
+
+    for path in puzzle.save(workspace, year, day)? {
+        println!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn print_status(workspace: &Workspace, year: u16, check: bool, record: bool) -> eyre::Result<()> {
+    let statuses = status::collect(workspace, year)?;
+    let current = Progress::from(statuses.as_slice());
+    print!("{}", status::calendar(&statuses));
+
+    if check {
+        let regressions = Progress::load(workspace, year)?.regressions(&current);
+        if !regressions.is_empty() {
+            let parts = regressions
+                .iter()
+                .map(|(day, part)| format!("day {} part {}", day, part))
+                .collect::<Vec<_>>();
+
+            return Err(eyre!("Parts regressed to unsolved: {}", parts.join(", ")));
+        }
+    }
+
+    if record {
+        current.save(workspace, year)?;
+    }
+
+    Ok(())
+}
+
+fn submit_answer(
+    workspace: &Workspace,
+    year: u16,
+    day: u8,
+    part: u8,
+    params: &[(String, i64)],
+    base_url: &str,
+) -> eyre::Result<()> {
+    let registered = registry::day(year, day).ok_or_else(|| eyre!("Day {} of {} is not registered", day, year))?;
+    let arguments = registered.arguments(params)?;
+    let answer = registered.solve(part, &workspace.read_input(year, day)?, &arguments).answer?;
+
+    let path = workspace.answer_book_path(year, day);
+    let mut answer_book = AnswerBook::load(&path)?;
+    submit::check_before_submitting(&answer_book, part, &answer)?;
+
+    println!("Submitting {} for day {} part {}", answer, day, part);
+    match submit::submit(base_url, year, day, part, &answer, &http::session(workspace)?)? {
+        Outcome::Correct => {
+            answer_book.set(part, answer);
+            answer_book.save(&path)?;
+            println!("Correct, recorded in {}", path.display());
+            Ok(())
+        }
+        Outcome::Incorrect { hint } => Err(eyre!("Wrong answer{}", hint.map(|x| format!(", {}", x)).unwrap_or_default())),
+        Outcome::TooSoon { wait } => Err(eyre!("Submitted too soon{}", wait.map(|x| format!(". {}", x)).unwrap_or_default())),
+        Outcome::WrongLevel => Err(eyre!("Part {} of day {} is already solved or not unlocked yet", part, day)),
+        Outcome::Unrecognised(text) => Err(eyre!("Unrecognised response: {}", text)),
+    }
+}
+
+fn manage_vault(workspace: &Workspace, action: VaultAction) -> eyre::Result<()> {
+    let key_path = workspace.root().join(vault::KEY_FILE);
+    if let VaultAction::Keygen = action {
+        if key_path.exists() {
+            return Err(eyre!("{} already exists", key_path.display()));
+        }
+        fs::write(&key_path, Key::generate().to_hex())?;
+        println!("Wrote {}, share it with the team out of band", key_path.display());
+        return Ok(());
+    }
+
+    let key = Key::find(workspace.root())?;
+    let files = registry::YEARS
+        .iter()
+        .flat_map(|(year, _)| (1..=25).map(|day| (*year, day)))
+        .filter(|(year, day)| workspace.day_dir(*year, *day).exists())
+        .flat_map(|(year, day)| workspace.vault_files(year, day));
+
+    for path in files {
+        let relative = path.strip_prefix(workspace.root()).unwrap_or(&path).display();
+        match action {
+            VaultAction::Keygen => unreachable!("Handled above"),
+            VaultAction::Status => match vault::state(&key, &path)? {
+                State::Missing => {}
+                state => println!("{:<14} {}", format!("{:?}", state), relative),
+            },
+            VaultAction::Encrypt => {
+                if vault::seal(&key, &path)? {
+                    println!("Encrypted {}", relative);
+                }
+            }
+            VaultAction::Decrypt { force } => {
+                if vault::open(&key, &path, force)? {
+                    println!("Decrypted {}", relative);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> eyre::Result<()> {
+    let cli = Cli::parse();
+    let workspace = cli.root.map(Workspace::new).unwrap_or_default();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            params,
+            porcelain,
+            no_cache,
+            variant,
+            all_variants,
+        } => {
+            let variants = match (variant, all_variants) {
+                (Some(name), _) => VariantChoice::Named(name),
+                (None, true) => VariantChoice::All,
+                (None, false) => VariantChoice::First,
+            };
+            run(
+                &workspace,
+                cli.year,
+                day,
+                part,
+                &params,
+                RunOptions {
+                    porcelain,
+                    no_cache,
+                    variants,
+                },
+            )
+        }
+        Command::All {
+            no_cache,
+            timeout_ms,
+            jobs,
+        } => run_all(
+            &workspace,
+            cli.year,
+            AllOptions {
+                no_cache,
+                timeout: Duration::from_millis(timeout_ms),
+                jobs: jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |x| x.get())),
+            },
+        ),
+        Command::Compare {
+            day,
+            config,
+            timeout_ms,
+        } => compare_solvers(&workspace, cli.year, day, config, Duration::from_millis(timeout_ms)),
+        Command::Repl { day, params, script } => repl(&workspace, cli.year, day, &params, script),
+        Command::Watch { day, debounce_ms } => {
+            watch::watch(&workspace, cli.year, day, Duration::from_millis(debounce_ms))
+        }
+        Command::Serve {
+            address,
+            max_body_bytes,
+            timeout_ms,
+            jobs,
+        } => serve::serve(
+            &address,
+            ServeConfig {
+                year: cli.year,
+                max_body_bytes,
+                timeout: Duration::from_millis(timeout_ms),
+                workers: jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |x| x.get())),
+            },
+        ),
+        Command::Leaderboard { file, url, json } => leaderboard(&workspace, file, url, json),
+        Command::Puzzle { day, base_url } => fetch_puzzle(&workspace, cli.year, day, &base_url),
+        Command::Status { check, record } => print_status(&workspace, cli.year, check, record),
+        Command::Submit {
+            day,
+            part,
+            params,
+            base_url,
+        } => submit_answer(&workspace, cli.year, day, part, &params, &base_url),
+        Command::Vault { action } => manage_vault(&workspace, action),
+    }
+}
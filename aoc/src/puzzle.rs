@@ -0,0 +1,249 @@
+use crate::html::{self, Element, Node};
+use crate::http;
+use crate::workspace::Workspace;
+use eyre::{eyre, WrapErr};
+use std::fs;
+use std::path::PathBuf;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// A day's puzzle description converted to Markdown, along with the examples it contains.
+#[derive(Debug)]
+pub struct PuzzleText {
+    pub markdown: String,
+    pub examples: Vec<String>,
+}
+
+fn escape(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut escaped, c| {
+            if matches!(c, '\\' | '*' | '_' | '`') {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+            escaped
+        })
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.starts_with(char::is_whitespace) && !collapsed.is_empty() {
+        collapsed.insert(0, ' ');
+    }
+    if text.ends_with(char::is_whitespace) {
+        collapsed.push(' ');
+    }
+
+    collapsed
+}
+
+fn inline_code(text: &str) -> String {
+    match text.contains('`') {
+        true => format!("`` {} ``", text),
+        false => format!("`{}`", text),
+    }
+}
+
+fn absolute_url(href: &str, base_url: &str) -> String {
+    match href.starts_with('/') {
+        true => format!("{}{}", base_url.trim_end_matches('/'), href),
+        false => href.to_string(),
+    }
+}
+
+fn render_inline(nodes: &[Node], base_url: &str) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            Node::Text(text) => escape(&collapse_whitespace(text)),
+            Node::Element(element) => match element.name.as_str() {
+                // Answers are written as <code><em>11</em></code>, which Markdown can only
+                // express with the emphasis outside the code span.
+                "code" => match element.children.as_slice() {
+                    [Node::Element(em)] if em.name == "em" => format!("*{}*", inline_code(&em.text())),
+                    _ => inline_code(&element.text()),
+                },
+                "em" => format!("*{}*", render_inline(&element.children, base_url).trim()),
+                "a" => format!(
+                    "[{}]({})",
+                    render_inline(&element.children, base_url).trim(),
+                    absolute_url(element.attribute("href").unwrap_or(""), base_url)
+                ),
+                "br" => "\n".to_string(),
+                _ => render_inline(&element.children, base_url),
+            },
+        })
+        .collect()
+}
+
+fn render_blocks(element: &Element, base_url: &str, markdown: &mut String) {
+    for child in &element.children {
+        match child {
+            Node::Text(text) if text.trim().is_empty() => {}
+            Node::Text(text) => {
+                markdown.push_str(escape(text.trim()).as_str());
+                markdown.push_str("\n\n");
+            }
+            Node::Element(element) => match element.name.as_str() {
+                "h2" => {
+                    markdown.push_str(&format!("## {}\n\n", render_inline(&element.children, base_url).trim()))
+                }
+                "p" => markdown.push_str(&format!("{}\n\n", render_inline(&element.children, base_url).trim())),
+                "pre" => {
+                    let text = element.text();
+                    let newline = if text.ends_with('\n') { "" } else { "\n" };
+                    markdown.push_str(&format!("```\n{}{}```\n\n", text, newline));
+                }
+                "ul" => {
+                    element
+                        .find_all("li")
+                        .iter()
+                        .for_each(|li| markdown.push_str(&format!("- {}\n", render_inline(&li.children, base_url).trim())));
+                    markdown.push('\n');
+                }
+                _ => render_blocks(element, base_url, markdown),
+            },
+        }
+    }
+}
+
+impl PuzzleText {
+    /// Converts every `<article class="day-desc">` on a puzzle page, which includes part 2 once
+    /// it is unlocked.
+    pub fn extract(page: &str, base_url: &str) -> eyre::Result<PuzzleText> {
+        let root = html::parse(page);
+        let articles = root
+            .find_all("article")
+            .into_iter()
+            .filter(|x| x.has_class("day-desc"))
+            .collect::<Vec<_>>();
+
+        if articles.is_empty() {
+            return Err(eyre!("No puzzle description found on the page"));
+        }
+
+        let mut markdown = String::new();
+        articles.iter().for_each(|x| render_blocks(x, base_url, &mut markdown));
+
+        let examples = articles
+            .iter()
+            .flat_map(|x| x.find_all("pre"))
+            .map(|x| x.text())
+            .collect();
+
+        Ok(PuzzleText {
+            markdown: markdown.trim_end().to_string() + "\n",
+            examples,
+        })
+    }
+
+    /// Writes `PUZZLE.md` and any examples not already saved under `fixtures/`, returning the
+    /// paths written.
+    pub fn save(&self, workspace: &Workspace, year: u16, day: u8) -> eyre::Result<Vec<PathBuf>> {
+        let puzzle_path = workspace.day_dir(year, day).join("PUZZLE.md");
+        fs::write(&puzzle_path, &self.markdown)
+            .wrap_err_with(|| format!("Could not write {}", puzzle_path.display()))?;
+
+        let fixtures_dir = workspace.fixtures_dir(year, day);
+        fs::create_dir_all(&fixtures_dir)?;
+
+        let mut written = vec![puzzle_path];
+        for (i, example) in self.examples.iter().enumerate() {
+            let path = fixtures_dir.join(format!("example_{}.txt", i + 1));
+            if !path.exists() {
+                fs::write(&path, example)?;
+                written.push(path);
+            }
+        }
+
+        Ok(written)
+    }
+}
+
+pub fn fetch(base_url: &str, year: u16, day: u8, session: &str) -> eyre::Result<PuzzleText> {
+    let url = format!("{}/{}/day/{}", base_url.trim_end_matches('/'), year, day);
+
+    PuzzleText::extract(&http::get(&url, session)?, base_url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use tiny_http::{Response, Server};
+
+    const PAGE: &str = include_str!("../fixtures/puzzle.html");
+
+    #[test]
+    fn should_convert_articles_to_markdown() {
+        // When
+        let puzzle = PuzzleText::extract(PAGE, DEFAULT_BASE_URL).unwrap();
+
+        // Then
+        assert_eq!(
+            "## --- Day 1: Sample Sorting ---
+
+The elves have two lists of numbers & want them *compared*.
+
+For example:
+
+```
+3   4
+4   3
+2   5
+```
+
+- Pair up the *smallest* numbers.
+- Add up the distances, like `2 - 1`.
+
+In the example, the total is *`11`*. Read the [about page](https://adventofcode.com/2024/about) for more.
+
+## --- Part Two ---
+
+Now count how often each number in the left list appears in the right list:
+
+```
+1 3
+2 3
+```
+
+The score is *`31`*.
+",
+            puzzle.markdown
+        );
+    }
+
+    #[test]
+    fn should_extract_examples() {
+        // When
+        let puzzle = PuzzleText::extract(PAGE, DEFAULT_BASE_URL).unwrap();
+
+        // Then
+        assert_eq!(vec!["3   4\n4   3\n2   5\n", "1 3\n2 3\n"], puzzle.examples);
+    }
+
+    #[test]
+    fn should_fetch_from_configured_base_url() {
+        // Given
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        thread::spawn(move || {
+            let request = server.recv().unwrap();
+            let authorised = request
+                .headers()
+                .iter()
+                .any(|x| x.field.equiv("Cookie") && x.value == "session=abc");
+            let response = match (request.url(), authorised) {
+                ("/2024/day/1", true) => Response::from_string(PAGE),
+                _ => Response::from_string("").with_status_code(404),
+            };
+            request.respond(response).unwrap();
+        });
+
+        // When
+        let puzzle = fetch(&base_url, 2024, 1, "abc").unwrap();
+
+        // Then
+        assert!(puzzle.markdown.contains(&format!("[about page]({}/2024/about)", base_url)));
+    }
+}
@@ -0,0 +1,298 @@
+use answer::Answer;
+use cancel::CancellationToken;
+use eyre::eyre;
+use parsing::ParseError;
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+pub(crate) mod y2024;
+
+/// The year commands use unless given `--year`.
+pub const LATEST_YEAR: u16 = 2024;
+
+/// Every registered year and its days, oldest first.
+pub static YEARS: [(u16, &[Day]); 1] = [(2024, &y2024::DAYS)];
+
+/// Solves a part from its input. Solvers with long loops check the token and give up with
+/// `Cancelled` once it is cancelled.
+pub type Solver = fn(&str, &Arguments, &CancellationToken) -> eyre::Result<Answer>;
+
+/// A value a day needs besides its input, such as the size of day 14's grid.
+#[derive(Debug)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub default: i64,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Arguments {
+    values: BTreeMap<&'static str, i64>,
+}
+
+impl Arguments {
+    pub fn get(&self, name: &str) -> i64 {
+        self.values[name]
+    }
+}
+
+impl Display for Arguments {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values = self
+            .values
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", values.join(","))
+    }
+}
+
+/// One of several ways of solving a part, such as a naive solver kept next to a faster one.
+#[derive(Clone, Copy)]
+pub struct Variant {
+    pub name: &'static str,
+    pub solver: Solver,
+}
+
+pub const DEFAULT_VARIANT: &str = "default";
+
+#[derive(Clone, Copy)]
+pub enum Part {
+    Solved(Solver),
+    /// A part solved in more than one way. The first variant is used unless another is asked
+    /// for, and every variant should give the same answer.
+    Variants(&'static [Variant]),
+    /// A part with no real solution yet, such as one solved by hand.
+    Unsolved,
+}
+
+impl Part {
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Part::Unsolved)
+    }
+
+    pub fn variants(&self) -> Vec<Variant> {
+        match self {
+            Part::Solved(solver) => vec![Variant {
+                name: DEFAULT_VARIANT,
+                solver: *solver,
+            }],
+            Part::Variants(variants) => variants.to_vec(),
+            Part::Unsolved => Vec::new(),
+        }
+    }
+}
+
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub parameters: &'static [Parameter],
+    pub parts: [Part; 2],
+}
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: eyre::Result<Answer>,
+    pub elapsed: Duration,
+}
+
+impl Day {
+    /// Fills in the default for every parameter not given in `overrides`.
+    pub fn arguments(&self, overrides: &[(String, i64)]) -> eyre::Result<Arguments> {
+        let mut values: BTreeMap<&'static str, i64> =
+            self.parameters.iter().map(|x| (x.name, x.default)).collect();
+
+        for (name, value) in overrides {
+            let parameter = self
+                .parameters
+                .iter()
+                .find(|x| x.name == name)
+                .ok_or_else(|| eyre!("Day {} has no parameter {:?}", self.day, name))?;
+            values.insert(parameter.name, *value);
+        }
+
+        Ok(Arguments { values })
+    }
+
+    pub fn part(&self, part: u8) -> Part {
+        self.parts[usize::from(part) - 1]
+    }
+
+    pub fn variant(&self, part: u8, name: &str) -> eyre::Result<Variant> {
+        let variants = self.part(part).variants();
+        variants.iter().find(|x| x.name == name).copied().ok_or_else(|| {
+            let names = variants.iter().map(|x| x.name).collect::<Vec<_>>();
+            eyre!("Part {} of day {} has no variant {:?}, only {:?}", part, self.day, name, names)
+        })
+    }
+
+    /// Solves a part with its first variant.
+    pub fn solve(&self, part: u8, input: &str, arguments: &Arguments) -> PartRun {
+        match self.part(part).variants().first() {
+            Some(variant) => self.solve_with(part, variant, input, arguments),
+            None => PartRun {
+                part,
+                answer: Err(eyre!("Part {} of day {} is not solved yet", part, self.day)),
+                elapsed: Duration::ZERO,
+            },
+        }
+    }
+
+    pub fn solve_with(&self, part: u8, variant: &Variant, input: &str, arguments: &Arguments) -> PartRun {
+        self.solve_cancellable(part, variant, input, arguments, &CancellationToken::new())
+    }
+
+    pub fn solve_cancellable(
+        &self,
+        part: u8,
+        variant: &Variant,
+        input: &str,
+        arguments: &Arguments,
+        cancel: &CancellationToken,
+    ) -> PartRun {
+        let start = Instant::now();
+        let answer = (variant.solver)(&parsing::normalise(input), arguments, cancel);
+
+        PartRun {
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+pub(crate) fn parse<T>(input: &str) -> eyre::Result<T>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    T::from_str(input).map_err(|e| eyre!("Could not parse input: {:?}", e))
+}
+
+pub(crate) fn parse_borrowed<'a, T>(input: &'a str) -> eyre::Result<T>
+where
+    T: TryFrom<&'a str>,
+    T::Error: Debug,
+{
+    T::try_from(input).map_err(|e| eyre!("Could not parse input: {:?}", e))
+}
+
+pub(crate) fn parse_spanned<T>(input: &str) -> eyre::Result<T>
+where
+    T: FromStr<Err = ParseError>,
+{
+    T::from_str(input).map_err(|e| eyre!("Could not parse input: {}", e.render(input)))
+}
+
+/// The days registered for a year, or none if the year isn't registered.
+pub fn days(year: u16) -> &'static [Day] {
+    YEARS.iter().find(|(x, _)| *x == year).map_or(&[], |(_, days)| days)
+}
+
+pub fn day(year: u16, day: u8) -> Option<&'static Day> {
+    days(year).iter().find(|x| x.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_solve_registered_day() {
+        // Given
+        let input = "3 4\n4 3\n2 5\n1 3\n3 9\n3 3";
+        let day = day(2024, 1).unwrap();
+
+        // When
+        let run = day.solve(2, input, &Arguments::default());
+
+        // Then
+        assert_eq!(2, run.part);
+        assert_eq!(Answer::from(31), run.answer.unwrap());
+    }
+
+    #[test]
+    fn should_normalise_input_before_solving() {
+        // Given
+        let input = "\u{feff}125 17\r\n";
+        let day = day(2024, 11).unwrap();
+
+        // When
+        let run = day.solve(1, input, &Arguments::default());
+
+        // Then
+        assert_eq!(Answer::from(55312), run.answer.unwrap());
+    }
+
+    #[test]
+    fn should_solve_every_variant() {
+        // Given
+        let input = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+        let day = day(2024, 6).unwrap();
+
+        // When
+        let answers = day
+            .part(2)
+            .variants()
+            .iter()
+            .map(|x| day.solve_with(2, x, input, &Arguments::default()).answer.unwrap())
+            .collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(vec![Answer::from(6), Answer::from(6)], answers);
+        assert!(day.variant(2, "clone_map").is_ok());
+        assert!(day.variant(1, "clone_map").is_err());
+    }
+
+    #[test]
+    fn should_fail_unsolved_part() {
+        // Given
+        let day = day(2024, 14).unwrap();
+
+        // When
+        let run = day.solve(2, "p=0,4 v=3,-3", &day.arguments(&[]).unwrap());
+
+        // Then
+        assert_eq!("Part 2 of day 14 is not solved yet", run.answer.unwrap_err().to_string());
+    }
+
+    #[test]
+    fn should_override_parameter_defaults() {
+        // Given
+        let day = day(2024, 14).unwrap();
+
+        // When
+        let arguments = day.arguments(&[("width".to_string(), 11)]).unwrap();
+
+        // Then
+        assert_eq!(11, arguments.get("width"));
+        assert_eq!(103, arguments.get("height"));
+    }
+
+    #[test]
+    fn should_reject_unknown_parameter() {
+        // Given
+        let day = day(2024, 6).unwrap();
+
+        // When
+        let arguments = day.arguments(&[("width".to_string(), 11)]);
+
+        // Then
+        assert!(arguments.is_err());
+    }
+
+    #[test]
+    fn should_not_find_unregistered_day() {
+        // When
+        let unregistered_day = day(2024, 25);
+        let unregistered_year = day(2015, 1);
+
+        // Then
+        assert!(unregistered_day.is_none());
+        assert!(unregistered_year.is_none());
+        assert!(days(2015).is_empty());
+    }
+}
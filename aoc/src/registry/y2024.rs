@@ -0,0 +1,218 @@
+use super::{parse, parse_borrowed, parse_spanned, Arguments, Day, Parameter, Part, Variant, DEFAULT_VARIANT};
+use eyre::eyre;
+
+pub(crate) fn day_14_dimensions(arguments: &Arguments) -> eyre::Result<y2024_day_14::Point> {
+    let dimension = |name| {
+        i32::try_from(arguments.get(name) - 1).map_err(|_| eyre!("{} is out of range", name))
+    };
+
+    Ok(y2024_day_14::Point::new(dimension("width")?, dimension("height")?))
+}
+
+pub static DAYS: [Day; 14] = [
+    Day {
+        year: 2024,
+        day: 1,
+        parameters: &[],
+        parts: [
+            Part::Variants(&[
+                Variant {
+                    name: DEFAULT_VARIANT,
+                    solver: |input, _, _| Ok(y2024_day_01::calculate_total_distance(input).into()),
+                },
+                Variant {
+                    name: "radix",
+                    solver: |input, _, _| Ok(parse_spanned::<y2024_day_01::SortedLists>(input)?.total_distance().into()),
+                },
+            ]),
+            Part::Variants(&[
+                Variant {
+                    name: DEFAULT_VARIANT,
+                    solver: |input, _, _| Ok(y2024_day_01::calculate_similarity_score(input).into()),
+                },
+                Variant {
+                    name: "radix",
+                    solver: |input, _, _| Ok(parse_spanned::<y2024_day_01::SortedLists>(input)?.similarity_score().into()),
+                },
+            ]),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 2,
+        parameters: &[Parameter { name: "tolerance", default: 1 }],
+        parts: [
+            Part::Solved(|input, _, _| Ok(y2024_day_02::get_safe_level_report_count(input)?.into())),
+            Part::Solved(|input, arguments, _| {
+                let tolerance = usize::try_from(arguments.get("tolerance"))
+                    .map_err(|_| eyre!("tolerance is out of range"))?;
+                Ok(y2024_day_02::get_tolerant_safe_report_count(input, tolerance)?.into())
+            }),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 3,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, _| Ok(y2024_day_03::part_1(input)?.into())),
+            Part::Solved(|input, _, _| Ok(y2024_day_03::part_2(input)?.into())),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 4,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, _| Ok(y2024_day_04::part_1::solve(input)?.into())),
+            Part::Solved(|input, _, _| Ok(y2024_day_04::part_2::solve(input)?.into())),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 5,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, _| Ok(parse_spanned::<y2024_day_05::Puzzle>(input)?.sum_of_correct_updates().into())),
+            Part::Solved(|input, _, _| Ok(parse_spanned::<y2024_day_05::Puzzle>(input)?.sum_of_incorrect_updates().into())),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 6,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, cancel| {
+                let puzzle = parse_borrowed::<y2024_day_06::Puzzle>(input)?;
+                Ok(puzzle.try_distinct_positions_visited(cancel)?.into())
+            }),
+            Part::Variants(&[
+                Variant {
+                    name: "resume",
+                    solver: |input, _, cancel| {
+                        let puzzle = parse_borrowed::<y2024_day_06::Puzzle>(input)?;
+                        let loops = puzzle.try_potential_loop_opportunities_with(y2024_day_06::LoopSearch::Resume, cancel)?;
+                        Ok(loops.into())
+                    },
+                },
+                Variant {
+                    name: "clone_map",
+                    solver: |input, _, cancel| {
+                        let puzzle = parse_borrowed::<y2024_day_06::Puzzle>(input)?;
+                        let loops = puzzle.try_potential_loop_opportunities_with(y2024_day_06::LoopSearch::CloneMap, cancel)?;
+                        Ok(loops.into())
+                    },
+                },
+            ]),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 7,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, _| Ok(parse_spanned::<y2024_day_07::Puzzle>(input)?.part_1().into())),
+            Part::Solved(|input, _, _| Ok(parse_spanned::<y2024_day_07::Puzzle>(input)?.part_2().into())),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 8,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, _| Ok(parse::<y2024_day_08::Puzzle>(input)?.part_1().into())),
+            Part::Solved(|input, _, _| Ok(parse::<y2024_day_08::Puzzle>(input)?.part_2().into())),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 9,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, _| Ok(parse::<y2024_day_09::Puzzle>(input)?.part_1().into())),
+            Part::Solved(|input, _, _| Ok(parse::<y2024_day_09::Puzzle>(input)?.part_2().into())),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 10,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, _| Ok(parse::<y2024_day_10::Puzzle>(input)?.part_1().into())),
+            Part::Solved(|input, _, _| Ok(parse::<y2024_day_10::Puzzle>(input)?.part_2().into())),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 11,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, cancel| Ok(parse_spanned::<y2024_day_11::Puzzle>(input)?.try_part_1(cancel)?.into())),
+            Part::Solved(|input, _, cancel| Ok(parse_spanned::<y2024_day_11::Puzzle>(input)?.try_part_2(cancel)?.into())),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 12,
+        parameters: &[],
+        parts: [
+            Part::Solved(|input, _, _| Ok(parse_spanned::<y2024_day_12::Puzzle>(input)?.part_1().into())),
+            Part::Solved(|input, _, _| Ok(parse_spanned::<y2024_day_12::Puzzle>(input)?.part_2().into())),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 13,
+        parameters: &[],
+        parts: [
+            Part::Variants(&[
+                Variant {
+                    name: "algebraic",
+                    solver: |input, _, _| {
+                        let puzzle = parse_spanned::<y2024_day_13::Puzzle>(input)?;
+                        Ok(puzzle.part_1_with(y2024_day_13::PrizeSearch::Algebraic).into())
+                    },
+                },
+                Variant {
+                    name: "binary_search",
+                    solver: |input, _, _| {
+                        let puzzle = parse_spanned::<y2024_day_13::Puzzle>(input)?;
+                        Ok(puzzle.part_1_with(y2024_day_13::PrizeSearch::BinarySearch).into())
+                    },
+                },
+            ]),
+            Part::Variants(&[
+                Variant {
+                    name: "algebraic",
+                    solver: |input, _, _| {
+                        let puzzle = parse_spanned::<y2024_day_13::Puzzle>(input)?;
+                        Ok(puzzle.part_2_with(y2024_day_13::PrizeSearch::Algebraic).into())
+                    },
+                },
+                Variant {
+                    name: "binary_search",
+                    solver: |input, _, _| {
+                        let puzzle = parse_spanned::<y2024_day_13::Puzzle>(input)?;
+                        Ok(puzzle.part_2_with(y2024_day_13::PrizeSearch::BinarySearch).into())
+                    },
+                },
+            ]),
+        ],
+    },
+    Day {
+        year: 2024,
+        day: 14,
+        parameters: &[
+            Parameter { name: "width", default: 101 },
+            Parameter { name: "height", default: 103 },
+        ],
+        parts: [
+            Part::Solved(|input, arguments, _| {
+                let puzzle = parse_spanned::<y2024_day_14::Puzzle>(input)?;
+                Ok(puzzle.part_1(day_14_dimensions(arguments)?).into())
+            }),
+            // Solved partially by hand, see `y2024_day_14::Puzzle::part_2`.
+            Part::Unsolved,
+        ],
+    },
+];
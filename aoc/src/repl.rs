@@ -0,0 +1,504 @@
+use crate::registry::Arguments;
+use eyre::{eyre, WrapErr};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+mod y2024;
+
+/// Every year with explorable days, oldest first.
+pub static EXPLORERS: [(u16, &[Explorer]); 1] = [(2024, &y2024::EXPLORERS)];
+
+/// How an argument is written on the command line.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Kind {
+    /// A whole number, e.g. `100`.
+    Integer,
+    /// An `x,y` pair, e.g. `3,6`.
+    Point,
+    /// Comma separated whole numbers, e.g. `75,47,61`.
+    Integers,
+}
+
+impl Kind {
+    fn parse(&self, word: &str) -> Option<Value> {
+        let integers = word.split(',').map(|x| x.parse().ok()).collect::<Option<Vec<i64>>>()?;
+
+        match (self, integers.as_slice()) {
+            (Kind::Integer, [x]) => Some(Value::Integer(*x)),
+            (Kind::Point, [x, y]) => Some(Value::Point(*x, *y)),
+            (Kind::Integers, _) => Some(Value::Integers(integers)),
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &'static str {
+        match self {
+            Kind::Integer => "n",
+            Kind::Point => "x,y",
+            Kind::Integers => "a,b,...",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Point(i64, i64),
+    Integers(Vec<i64>),
+}
+
+#[derive(Debug)]
+pub struct Argument {
+    pub name: &'static str,
+    pub kind: Kind,
+}
+
+/// The values a command was called with. Asking for an argument the command doesn't declare,
+/// or as the wrong kind, is a mistake in the command table and panics.
+#[derive(Debug, Default)]
+pub struct Values {
+    values: Vec<(&'static str, Value)>,
+}
+
+impl Values {
+    fn get(&self, name: &str) -> &Value {
+        self.values
+            .iter()
+            .find(|(x, _)| *x == name)
+            .map(|(_, value)| value)
+            .unwrap_or_else(|| panic!("Command has no argument {:?}", name))
+    }
+
+    pub fn integer(&self, name: &str) -> i64 {
+        match self.get(name) {
+            Value::Integer(x) => *x,
+            other => panic!("Argument {:?} is {:?}, not an integer", name, other),
+        }
+    }
+
+    pub fn point(&self, name: &str) -> (i64, i64) {
+        match self.get(name) {
+            Value::Point(x, y) => (*x, *y),
+            other => panic!("Argument {:?} is {:?}, not a point", name, other),
+        }
+    }
+
+    pub fn integers(&self, name: &str) -> &[i64] {
+        match self.get(name) {
+            Value::Integers(x) => x,
+            other => panic!("Argument {:?} is {:?}, not a list of integers", name, other),
+        }
+    }
+}
+
+/// A named query on a loaded puzzle of type `P`.
+pub struct Command<P> {
+    pub name: &'static str,
+    pub arguments: &'static [Argument],
+    pub help: &'static str,
+    pub run: fn(&P, &Values) -> eyre::Result<String>,
+}
+
+impl<P> Command<P> {
+    fn signature(&self) -> Signature {
+        Signature {
+            name: self.name,
+            arguments: self.arguments,
+            help: self.help,
+        }
+    }
+}
+
+/// A command as shown by `help`, without the puzzle it runs on.
+#[derive(Debug, Clone, Copy)]
+pub struct Signature {
+    pub name: &'static str,
+    pub arguments: &'static [Argument],
+    pub help: &'static str,
+}
+
+impl Signature {
+    fn usage(&self) -> String {
+        let arguments = self.arguments.iter().map(|x| format!(" <{}: {}>", x.name, x.kind.syntax()));
+
+        std::iter::once(self.name.to_string()).chain(arguments).collect()
+    }
+}
+
+/// A puzzle parsed once and the commands that query it.
+pub trait Session {
+    fn commands(&self) -> Vec<Signature>;
+    fn run(&self, name: &str, values: &Values) -> eyre::Result<String>;
+}
+
+struct Loaded<P: 'static> {
+    puzzle: P,
+    commands: &'static [Command<P>],
+}
+
+impl<P> Session for Loaded<P> {
+    fn commands(&self) -> Vec<Signature> {
+        self.commands.iter().map(Command::signature).collect()
+    }
+
+    fn run(&self, name: &str, values: &Values) -> eyre::Result<String> {
+        let command = self
+            .commands
+            .iter()
+            .find(|x| x.name == name)
+            .ok_or_else(|| eyre!("Unknown command {:?}", name))?;
+
+        (command.run)(&self.puzzle, values)
+    }
+}
+
+pub fn session<P>(puzzle: P, commands: &'static [Command<P>]) -> Box<dyn Session> {
+    Box::new(Loaded { puzzle, commands })
+}
+
+/// Parses a day's input into a session.
+pub type Loader = fn(&str, &Arguments) -> eyre::Result<Box<dyn Session>>;
+
+pub struct Explorer {
+    pub year: u16,
+    pub day: u8,
+    pub load: Loader,
+}
+
+pub fn explorer(year: u16, day: u8) -> Option<&'static Explorer> {
+    EXPLORERS
+        .iter()
+        .filter(|(x, _)| *x == year)
+        .flat_map(|(_, explorers)| explorers.iter())
+        .find(|x| x.day == day)
+}
+
+/// Runs lines typed or scripted against a session, keeping a transcript of its commands so the
+/// session can be saved as a script.
+pub struct Repl {
+    session: Box<dyn Session>,
+    transcript: Vec<(String, String)>,
+}
+
+const BUILT_INS: [(&str, &str); 4] = [
+    ("help [command]", "Lists the commands, or describes one"),
+    ("history", "Lists the commands run so far"),
+    ("save <file>", "Writes the commands run so far and their output as a script"),
+    ("quit", "Leaves the REPL"),
+];
+
+impl Repl {
+    pub fn new(session: Box<dyn Session>) -> Repl {
+        Repl {
+            session,
+            transcript: Vec::new(),
+        }
+    }
+
+    /// Runs a line and gives what it printed, or `None` once asked to quit. Failures are
+    /// printed as `error: ...` rather than returned, so a script can expect them too.
+    pub fn eval(&mut self, line: &str) -> Option<String> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let output = match words.as_slice() {
+            [] => String::new(),
+            ["quit" | "exit"] => return None,
+            ["help"] => self.help(),
+            ["help", name] => self.describe(name),
+            ["history"] => self.transcript.iter().map(|(x, _)| format!("{}\n", x)).collect(),
+            ["save", path] => match self.save(Path::new(path)) {
+                Ok(()) => format!("Saved {} commands to {}\n", self.transcript.len(), path),
+                Err(e) => format!("error: {:#}\n", e),
+            },
+            [name, words @ ..] => {
+                let output = match self.run(name, words) {
+                    Ok(output) => format!("{}\n", output.trim_end()),
+                    Err(e) => format!("error: {:#}\n", e),
+                };
+                self.transcript.push((words_line(name, words), output.clone()));
+                output
+            }
+        };
+
+        Some(output)
+    }
+
+    fn run(&self, name: &str, words: &[&str]) -> eyre::Result<String> {
+        let signature = self.signature(name)?;
+        if words.len() != signature.arguments.len() {
+            return Err(eyre!("Usage: {}", signature.usage()));
+        }
+
+        let values = signature
+            .arguments
+            .iter()
+            .zip(words)
+            .map(|(argument, word)| {
+                let value = argument
+                    .kind
+                    .parse(word)
+                    .ok_or_else(|| eyre!("{} should be {}, not {:?}", argument.name, argument.kind.syntax(), word))?;
+                Ok((argument.name, value))
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        self.session.run(name, &Values { values })
+    }
+
+    fn signature(&self, name: &str) -> eyre::Result<Signature> {
+        self.session
+            .commands()
+            .into_iter()
+            .find(|x| x.name == name)
+            .ok_or_else(|| eyre!("Unknown command {:?}, try `help`", name))
+    }
+
+    fn help(&self) -> String {
+        let commands = self.session.commands();
+        let rows = commands
+            .iter()
+            .map(|x| (x.usage(), x.help))
+            .chain(BUILT_INS.iter().map(|(usage, help)| (usage.to_string(), *help)))
+            .collect::<Vec<_>>();
+        let width = rows.iter().map(|(usage, _)| usage.len()).max().unwrap_or(0);
+
+        rows.iter().fold(String::new(), |mut output, (usage, help)| {
+            let _ = writeln!(output, "{:<width$}  {}", usage, help, width = width);
+            output
+        })
+    }
+
+    fn describe(&self, name: &str) -> String {
+        match self.signature(name) {
+            Ok(signature) => format!("{}\n  {}\n", signature.usage(), signature.help),
+            Err(e) => format!("error: {}\n", e),
+        }
+    }
+
+    fn save(&self, path: &Path) -> eyre::Result<()> {
+        let script = self
+            .transcript
+            .iter()
+            .map(|(command, output)| format!("> {}\n{}", command, output))
+            .collect::<String>();
+
+        fs::write(path, script).wrap_err_with(|| format!("Could not write {}", path.display()))
+    }
+}
+
+fn words_line(name: &str, words: &[&str]) -> String {
+    std::iter::once(name).chain(words.iter().copied()).collect::<Vec<_>>().join(" ")
+}
+
+/// A saved session: `> ` lines are commands, and the lines after each command are the output
+/// it should print. A command with nothing after it is run without being checked. Blank lines
+/// and `#` comments are skipped.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Script {
+    pub steps: Vec<Step>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Step {
+    pub line: usize,
+    pub command: String,
+    pub expected: Option<String>,
+}
+
+impl FromStr for Script {
+    type Err = eyre::Report;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut steps: Vec<Step> = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match (line.strip_prefix("> "), steps.last_mut()) {
+                (Some(command), _) => steps.push(Step {
+                    line: i + 1,
+                    command: command.trim().to_string(),
+                    expected: None,
+                }),
+                (None, Some(step)) => {
+                    let expected = step.expected.get_or_insert_with(String::new);
+                    expected.push_str(line);
+                    expected.push('\n');
+                }
+                (None, None) => return Err(eyre!("Line {} is output before any `> ` command", i + 1)),
+            }
+        }
+
+        Ok(Script { steps })
+    }
+}
+
+/// A scripted command whose output differed from the script.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Mismatch {
+    pub line: usize,
+    pub command: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// Runs every step of a script, writing each command and its output to `log` as it goes.
+pub fn run_script(repl: &mut Repl, script: &Script, log: &mut impl std::io::Write) -> eyre::Result<Vec<Mismatch>> {
+    let mut mismatches = Vec::new();
+
+    for step in &script.steps {
+        writeln!(log, "> {}", step.command)?;
+        let Some(actual) = repl.eval(&step.command) else {
+            break;
+        };
+        write!(log, "{}", actual)?;
+
+        if let Some(expected) = step.expected.as_ref().filter(|x| **x != actual) {
+            writeln!(log, "# line {}: expected\n{}", step.line, expected.trim_end())?;
+            mismatches.push(Mismatch {
+                line: step.line,
+                command: step.command.clone(),
+                expected: expected.clone(),
+                actual,
+            });
+        }
+    }
+
+    Ok(mismatches)
+}
+
+/// Reads lines from the terminal until `quit` or end of input, keeping their history in
+/// `history_path` between sessions.
+pub fn interact(repl: &mut Repl, prompt: &str, history_path: &Path) -> eyre::Result<()> {
+    let mut editor = DefaultEditor::new()?;
+    // There is no history the first time round.
+    let _ = editor.load_history(history_path);
+
+    loop {
+        let line = match editor.readline(prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(e) => return Err(e.into()),
+        };
+        if !line.trim().is_empty() {
+            editor.add_history_entry(line.as_str())?;
+        }
+
+        match repl.eval(&line) {
+            Some(output) => print!("{}", output),
+            None => break,
+        }
+    }
+
+    if let Some(parent) = history_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    editor.save_history(history_path)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Grid {
+        width: i64,
+    }
+
+    static GRID_COMMANDS: [Command<Grid>; 2] = [
+        Command {
+            name: "index",
+            arguments: &[Argument { name: "point", kind: Kind::Point }],
+            help: "The index of a point",
+            run: |grid, values| {
+                let (x, y) = values.point("point");
+                Ok((y * grid.width + x).to_string())
+            },
+        },
+        Command {
+            name: "sum",
+            arguments: &[Argument { name: "values", kind: Kind::Integers }],
+            help: "Adds up the values",
+            run: |_, values| Ok(values.integers("values").iter().sum::<i64>().to_string()),
+        },
+    ];
+
+    fn repl() -> Repl {
+        Repl::new(session(Grid { width: 10 }, &GRID_COMMANDS))
+    }
+
+    #[test]
+    fn should_run_commands_with_typed_arguments() {
+        // Given
+        let mut repl = repl();
+
+        // When
+        let outputs = ["index 3,6", "sum 1,2,3", "index 3", "sum", "nope", "help index", "history"]
+            .map(|x| repl.eval(x).unwrap());
+
+        // Then
+        assert_eq!(
+            [
+                "63\n",
+                "6\n",
+                "error: point should be x,y, not \"3\"\n",
+                "error: Usage: sum <values: a,b,...>\n",
+                "error: Unknown command \"nope\", try `help`\n",
+                "index <point: x,y>\n  The index of a point\n",
+                "index 3,6\nsum 1,2,3\nindex 3\nsum\nnope\n",
+            ],
+            outputs
+        );
+        assert_eq!(None, repl.eval("quit"));
+    }
+
+    #[test]
+    fn should_check_scripts_against_their_expected_output() {
+        // Given
+        let script = Script::from_str("# A comment\n> index 1,1\n11\n> sum 2,2\n5\n> sum 7\n").unwrap();
+        let mut log = Vec::new();
+
+        // When
+        let mismatches = run_script(&mut repl(), &script, &mut log).unwrap();
+
+        // Then
+        assert_eq!(
+            vec![Mismatch {
+                line: 4,
+                command: "sum 2,2".to_string(),
+                expected: "5\n".to_string(),
+                actual: "4\n".to_string(),
+            }],
+            mismatches
+        );
+        assert_eq!("> index 1,1\n11\n> sum 2,2\n4\n# line 4: expected\n5\n> sum 7\n7\n", String::from_utf8(log).unwrap());
+        assert!(Script::from_str("11\n> index 1,1\n").is_err());
+    }
+
+    #[test]
+    fn should_save_sessions_as_scripts_that_pass() {
+        // Given
+        let path = std::env::temp_dir().join(format!("aoc-repl-{}.txt", std::process::id()));
+        let mut repl = repl();
+        for line in ["index 2,3", "help", "sum 1,x"] {
+            repl.eval(line);
+        }
+
+        // When
+        let saved = repl.eval(&format!("save {}", path.display())).unwrap();
+        let script = Script::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let mismatches = run_script(&mut self::repl(), &script, &mut Vec::new()).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Then
+        assert_eq!(format!("Saved 2 commands to {}\n", path.display()), saved);
+        assert_eq!(2, script.steps.len());
+        assert!(mismatches.is_empty());
+    }
+}
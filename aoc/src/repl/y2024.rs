@@ -0,0 +1,190 @@
+use super::{session, Argument, Command, Explorer, Kind, Values};
+use crate::registry::y2024::day_14_dimensions;
+use crate::registry::{parse, parse_borrowed, parse_spanned};
+use eyre::eyre;
+
+/// Turns a point argument into grid coordinates.
+fn coordinates(values: &Values, name: &str) -> eyre::Result<(usize, usize)> {
+    let (x, y) = values.point(name);
+
+    match (usize::try_from(x), usize::try_from(y)) {
+        (Ok(x), Ok(y)) => Ok((x, y)),
+        _ => Err(eyre!("{},{} is off the map", x, y)),
+    }
+}
+
+fn pages(values: &Values) -> eyre::Result<Vec<usize>> {
+    values
+        .integers("pages")
+        .iter()
+        .map(|x| usize::try_from(*x).map_err(|_| eyre!("{} is not a page number", x)))
+        .collect()
+}
+
+fn count(values: &Values, name: &str) -> eyre::Result<u32> {
+    let value = values.integer(name);
+    u32::try_from(value).map_err(|_| eyre!("{} should be between 0 and {}, not {}", name, u32::MAX, value))
+}
+
+static DAY_05: [Command<y2024_day_05::Puzzle>; 2] = [
+    Command {
+        name: "violations",
+        arguments: &[Argument { name: "pages", kind: Kind::Integers }],
+        help: "Lists the rules an update breaks",
+        run: |puzzle, values| {
+            let violations = puzzle.violated_rules(&pages(values)?);
+
+            Ok(match violations.is_empty() {
+                true => "follows every rule".to_string(),
+                false => violations.iter().map(|(before, after)| format!("{}|{}", before, after)).collect::<Vec<_>>().join(" "),
+            })
+        },
+    },
+    Command {
+        name: "correct",
+        arguments: &[Argument { name: "pages", kind: Kind::Integers }],
+        help: "Puts an update's pages in order",
+        run: |puzzle, values| {
+            let update = puzzle.correct_update(pages(values)?);
+            let middle = update.get((update.len().max(1) - 1) / 2).ok_or_else(|| eyre!("The update is empty"))?;
+
+            Ok(format!("{} (middle page {})", update.iter().map(usize::to_string).collect::<Vec<_>>().join(","), middle))
+        },
+    },
+];
+
+fn describe_patrol(patrol: Option<y2024_day_06::Patrol>) -> eyre::Result<String> {
+    match patrol {
+        Some(y2024_day_06::Patrol::Exits(positions)) => Ok(format!("exits after visiting {} positions", positions)),
+        Some(y2024_day_06::Patrol::Loops) => Ok("loops".to_string()),
+        None => Err(eyre!("The obstruction is off the map or where the guard starts")),
+    }
+}
+
+static DAY_06: [Command<y2024_day_06::Puzzle<'static>>; 2] = [
+    Command {
+        name: "path",
+        arguments: &[],
+        help: "Follows the guard until they leave the map or loop",
+        run: |puzzle, _| describe_patrol(puzzle.patrol(None)),
+    },
+    Command {
+        name: "obstruct",
+        arguments: &[Argument { name: "at", kind: Kind::Point }],
+        help: "Follows the guard with an extra obstruction",
+        run: |puzzle, values| describe_patrol(puzzle.patrol(Some(coordinates(values, "at")?))),
+    },
+];
+
+static DAY_09: [Command<y2024_day_09::Puzzle>; 2] = [
+    Command {
+        name: "blocks",
+        arguments: &[Argument { name: "moves", kind: Kind::Integer }],
+        help: "Shows the disk after part 1 moves this many blocks",
+        run: |puzzle, values| Ok(puzzle.layout_after_block_moves(count(values, "moves")? as usize)),
+    },
+    Command {
+        name: "files",
+        arguments: &[Argument { name: "moves", kind: Kind::Integer }],
+        help: "Shows the disk after part 2 tries to move this many files",
+        run: |puzzle, values| Ok(puzzle.layout_after_file_moves(count(values, "moves")? as usize)),
+    },
+];
+
+static DAY_12: [Command<y2024_day_12::Puzzle>; 1] = [Command {
+    name: "region",
+    arguments: &[Argument { name: "at", kind: Kind::Point }],
+    help: "Measures the region containing a point",
+    run: |puzzle, values| {
+        let (x, y) = coordinates(values, "at")?;
+        let region = puzzle.region_at(x, y).ok_or_else(|| eyre!("{},{} is off the map", x, y))?;
+
+        Ok(format!(
+            "plant {}, area {}, perimeter {}, sides {}",
+            region.plant, region.area, region.perimeter, region.sides
+        ))
+    },
+}];
+
+/// Day 14's robots and the grid they move on.
+struct Robots {
+    puzzle: y2024_day_14::Puzzle,
+    dimensions: y2024_day_14::Point,
+}
+
+static DAY_14: [Command<Robots>; 2] = [
+    Command {
+        name: "robots",
+        arguments: &[Argument { name: "seconds", kind: Kind::Integer }],
+        help: "Shows how many robots are on each tile after this many seconds",
+        run: |robots, values| Ok(robots.puzzle.render_at(&robots.dimensions, count(values, "seconds")?)),
+    },
+    Command {
+        name: "safety",
+        arguments: &[Argument { name: "seconds", kind: Kind::Integer }],
+        help: "Gives the safety factor after this many seconds",
+        run: |robots, values| Ok(robots.puzzle.safety_factor_at(&robots.dimensions, count(values, "seconds")?).to_string()),
+    },
+];
+
+pub static EXPLORERS: [Explorer; 5] = [
+    Explorer {
+        year: 2024,
+        day: 5,
+        load: |input, _| Ok(session(parse_spanned::<y2024_day_05::Puzzle>(input)?, &DAY_05)),
+    },
+    Explorer {
+        year: 2024,
+        day: 6,
+        load: |input, _| {
+            let puzzle = parse_borrowed::<y2024_day_06::Puzzle>(input)?;
+            Ok(session(puzzle.into_owned(), &DAY_06))
+        },
+    },
+    Explorer {
+        year: 2024,
+        day: 9,
+        load: |input, _| Ok(session(parse::<y2024_day_09::Puzzle>(input)?, &DAY_09)),
+    },
+    Explorer {
+        year: 2024,
+        day: 12,
+        load: |input, _| Ok(session(parse_spanned::<y2024_day_12::Puzzle>(input)?, &DAY_12)),
+    },
+    Explorer {
+        year: 2024,
+        day: 14,
+        load: |input, arguments| {
+            let robots = Robots {
+                puzzle: parse_spanned::<y2024_day_14::Puzzle>(input)?,
+                dimensions: day_14_dimensions(arguments)?,
+            };
+            Ok(session(robots, &DAY_14))
+        },
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::Arguments;
+    use crate::repl::{explorer, run_script, Repl, Script};
+    use std::str::FromStr;
+
+    #[test]
+    fn should_explore_the_day_06_example() {
+        // Given
+        let input = "....#.....\n.........#\n..........\n..#.......\n.......#..\n..........\n.#..^.....\n........#.\n#.........\n......#...";
+        let explorer = explorer(2024, 6).unwrap();
+        let mut repl = Repl::new((explorer.load)(input, &Arguments::default()).unwrap());
+        let script = Script::from_str(
+            "> path\nexits after visiting 41 positions\n> obstruct 3,6\nloops\n> obstruct -1,0\nerror: -1,0 is off the map\n",
+        )
+        .unwrap();
+
+        // When
+        let mismatches = run_script(&mut repl, &script, &mut Vec::new()).unwrap();
+
+        // Then
+        assert_eq!(Vec::<crate::repl::Mismatch>::new(), mismatches);
+    }
+}
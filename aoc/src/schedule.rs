@@ -0,0 +1,222 @@
+use crate::registry::{Arguments, Day, PartRun, Variant};
+use cancel::CancellationToken;
+use eyre::eyre;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::channel;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A part to solve alongside others.
+pub struct Job {
+    pub day: &'static Day,
+    pub part: u8,
+    pub variant: Variant,
+    pub input: Arc<vault::Input>,
+    pub arguments: Arguments,
+}
+
+#[derive(Debug)]
+pub enum JobOutcome {
+    Finished(PartRun),
+    /// The part was still running when its time ran out, so it was cancelled.
+    TimedOut(Duration),
+}
+
+/// Solves up to `workers` jobs at a time, each on its own thread, and cancels any part still
+/// running after `timeout`. A solver that never checks its token keeps running in the background
+/// once timed out, but no longer holds up the rest. A solver that panics finishes with an error.
+/// Outcomes are in the same order as `jobs`.
+pub fn solve_all(jobs: Vec<Job>, workers: usize, timeout: Duration) -> Vec<JobOutcome> {
+    let mut outcomes = (0..jobs.len()).map(|_| None).collect::<Vec<_>>();
+    let mut pending = jobs.into_iter().enumerate();
+    let mut running: Vec<(usize, Instant, CancellationToken)> = Vec::new();
+    let (sender, receiver) = channel();
+
+    loop {
+        while running.len() < workers.max(1) {
+            let Some((i, job)) = pending.next() else {
+                break;
+            };
+            let cancel = CancellationToken::new();
+            let (sender, token) = (sender.clone(), cancel.clone());
+
+            thread::spawn(move || {
+                let start = Instant::now();
+                let run = panic::catch_unwind(AssertUnwindSafe(|| {
+                    job.day.solve_cancellable(job.part, &job.variant, &job.input, &job.arguments, &token)
+                }))
+                .unwrap_or_else(|payload| PartRun {
+                    part: job.part,
+                    answer: Err(eyre!("Solver panicked: {}", panic_message(&*payload))),
+                    elapsed: start.elapsed(),
+                });
+                let _ = sender.send((i, run));
+            });
+            running.push((i, Instant::now() + timeout, cancel));
+        }
+
+        let Some(deadline) = running.iter().map(|(_, deadline, _)| *deadline).min() else {
+            break;
+        };
+
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            // A part that already timed out is no longer running, so its late result is dropped.
+            Ok((i, run)) => {
+                if let Some(position) = running.iter().position(|(x, _, _)| *x == i) {
+                    running.swap_remove(position);
+                    outcomes[i] = Some(JobOutcome::Finished(run));
+                }
+            }
+            Err(_) => {
+                let now = Instant::now();
+                running.retain(|(i, deadline, cancel)| {
+                    if *deadline > now {
+                        return true;
+                    }
+                    cancel.cancel();
+                    outcomes[*i] = Some(JobOutcome::TimedOut(timeout));
+                    false
+                });
+            }
+        }
+    }
+
+    outcomes
+        .into_iter()
+        .map(|x| x.expect("Every job should have finished or timed out"))
+        .collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Part;
+    use answer::Answer;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Barrier;
+
+    static BOTH_STARTED: Barrier = Barrier::new(2);
+    static NOTICED_CANCEL: AtomicBool = AtomicBool::new(false);
+
+    /// Each part only finishes once the other has started.
+    static TOGETHER: Day = Day {
+        year: 2024,
+        day: 1,
+        parameters: &[],
+        parts: [
+            Part::Solved(|_, _, _| {
+                BOTH_STARTED.wait();
+                Ok(Answer::from(1))
+            }),
+            Part::Solved(|_, _, _| {
+                BOTH_STARTED.wait();
+                Ok(Answer::from(2))
+            }),
+        ],
+    };
+
+    /// Part 1 runs until cancelled and part 2 finishes straight away.
+    static ENDLESS: Day = Day {
+        year: 2024,
+        day: 2,
+        parameters: &[],
+        parts: [
+            Part::Solved(|_, _, cancel| {
+                while !cancel.is_cancelled() {
+                    thread::yield_now();
+                }
+                NOTICED_CANCEL.store(true, Ordering::Relaxed);
+                Err(cancel::Cancelled.into())
+            }),
+            Part::Solved(|_, _, _| Ok(Answer::from(2))),
+        ],
+    };
+
+    /// Part 1 panics and part 2 finishes straight away.
+    static PANICKING: Day = Day {
+        year: 2024,
+        day: 3,
+        parameters: &[],
+        parts: [
+            Part::Solved(|_, _, _| panic!("out of bounds")),
+            Part::Solved(|_, _, _| Ok(Answer::from(2))),
+        ],
+    };
+
+    fn jobs(day: &'static Day) -> Vec<Job> {
+        let input = Arc::new(vault::Input::Owned(String::new()));
+        (1..=2)
+            .map(|part| Job {
+                day,
+                part,
+                variant: day.part(part).variants()[0],
+                input: input.clone(),
+                arguments: Arguments::default(),
+            })
+            .collect()
+    }
+
+    fn answers(outcomes: Vec<JobOutcome>) -> Vec<Option<Answer>> {
+        outcomes
+            .into_iter()
+            .map(|x| match x {
+                JobOutcome::Finished(run) => Some(run.answer.unwrap()),
+                JobOutcome::TimedOut(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_solve_parts_concurrently() {
+        // When
+        let outcomes = solve_all(jobs(&TOGETHER), 2, Duration::from_secs(30));
+
+        // Then
+        assert_eq!(vec![Some(Answer::from(1)), Some(Answer::from(2))], answers(outcomes));
+    }
+
+    #[test]
+    fn should_cancel_parts_that_run_out_of_time_and_carry_on() {
+        // When
+        let outcomes = solve_all(jobs(&ENDLESS), 1, Duration::from_millis(100));
+
+        // Then
+        assert_eq!(vec![None, Some(Answer::from(2))], answers(outcomes));
+        let start = Instant::now();
+        while !NOTICED_CANCEL.load(Ordering::Relaxed) && start.elapsed() < Duration::from_secs(5) {
+            thread::yield_now();
+        }
+        assert!(NOTICED_CANCEL.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn should_finish_panicking_parts_with_an_error_before_the_timeout() {
+        // Given
+        let start = Instant::now();
+
+        // When
+        let mut outcomes = solve_all(jobs(&PANICKING), 1, Duration::from_secs(30));
+
+        // Then
+        assert!(start.elapsed() < Duration::from_secs(30));
+        let Some(JobOutcome::Finished(run)) = outcomes.pop() else {
+            panic!("Part 2 should have finished");
+        };
+        assert_eq!(Answer::from(2), run.answer.unwrap());
+        let Some(JobOutcome::Finished(run)) = outcomes.pop() else {
+            panic!("The panicking part should have finished");
+        };
+        assert_eq!(1, run.part);
+        assert_eq!("Solver panicked: out of bounds", run.answer.unwrap_err().to_string());
+    }
+}
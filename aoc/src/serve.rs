@@ -0,0 +1,443 @@
+use answer::Answer;
+use cancel::CancellationToken;
+use crate::registry::{self, Day, PartRun};
+use eyre::eyre;
+use serde::Serialize;
+use std::io::Read;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::channel;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tiny_http::{Header, Request, Server};
+
+#[derive(Debug, Clone)]
+pub struct ServeConfig {
+    /// The year answered by routes without a `/years/{year}` prefix.
+    pub year: u16,
+    pub max_body_bytes: usize,
+    pub timeout: Duration,
+    /// How many requests are handled, and solvers run, at once.
+    pub workers: usize,
+}
+
+/// Counts the solver threads still running, including those whose requests timed out, since
+/// solvers only stop early where they check their token. New solves are refused at the limit, so
+/// that solvers ignoring cancellation can't pile up.
+#[derive(Debug)]
+pub struct Solvers {
+    limit: usize,
+    running: AtomicUsize,
+}
+
+/// A solver's place under the limit, given back when the solver's thread ends.
+struct SolverSlot(Arc<Solvers>);
+
+impl Solvers {
+    pub fn new(limit: usize) -> Arc<Solvers> {
+        Arc::new(Solvers { limit: limit.max(1), running: AtomicUsize::new(0) })
+    }
+
+    fn acquire(self: &Arc<Solvers>) -> Option<SolverSlot> {
+        self.running
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |x| (x < self.limit).then_some(x + 1))
+            .ok()
+            .map(|_| SolverSlot(self.clone()))
+    }
+}
+
+impl Drop for SolverSlot {
+    fn drop(&mut self) {
+        self.0.running.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Serialize)]
+struct ParameterListing {
+    name: &'static str,
+    default: i64,
+}
+
+#[derive(Serialize)]
+struct DayListing {
+    day: u8,
+    parts: usize,
+    parameters: Vec<ParameterListing>,
+}
+
+#[derive(Serialize)]
+struct SolveResponse {
+    day: u8,
+    part: u8,
+    answer: Option<Answer>,
+    elapsed_ns: u64,
+    diagnostics: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+impl Response {
+    fn json(status: u16, body: &impl Serialize) -> Response {
+        Response {
+            status,
+            body: serde_json::to_string(body).expect("Responses should serialise"),
+        }
+    }
+
+    fn error(status: u16, error: impl Into<String>) -> Response {
+        Response::json(status, &ErrorResponse { error: error.into() })
+    }
+}
+
+fn list_days(days: &[Day]) -> Response {
+    let days = days
+        .iter()
+        .map(|day| DayListing {
+            day: day.day,
+            parts: day.parts.len(),
+            parameters: day
+                .parameters
+                .iter()
+                .map(|x| ParameterListing { name: x.name, default: x.default })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+
+    Response::json(200, &days)
+}
+
+fn parse_query(query: &str) -> eyre::Result<Vec<(String, i64)>> {
+    query
+        .split('&')
+        .filter(|x| !x.is_empty())
+        .map(|pair| {
+            let (name, value) = pair
+                .split_once('=')
+                .ok_or_else(|| eyre!("Query parameter {:?} has no value", pair))?;
+            let value = value
+                .parse()
+                .map_err(|_| eyre!("Query parameter {:?} is not an integer", name))?;
+
+            Ok((name.to_string(), value))
+        })
+        .collect()
+}
+
+fn solve_with_timeout(
+    day: &'static Day,
+    part: u8,
+    input: String,
+    overrides: &[(String, i64)],
+    timeout: Duration,
+    solvers: &Arc<Solvers>,
+) -> Response {
+    let arguments = match day.arguments(overrides) {
+        Ok(arguments) => arguments,
+        Err(e) => return Response::error(400, e.to_string()),
+    };
+    let Some(slot) = solvers.acquire() else {
+        return Response::error(503, format!("All {} solvers are busy", solvers.limit));
+    };
+
+    let cancel = CancellationToken::new();
+    let token = cancel.clone();
+    let (sender, receiver) = channel::<PartRun>();
+    thread::spawn(move || {
+        let _slot = slot;
+        let run = match day.part(part).variants().first() {
+            Some(variant) => day.solve_cancellable(part, variant, &input, &arguments, &token),
+            None => day.solve(part, &input, &arguments),
+        };
+        sender.send(run)
+    });
+
+    // Solvers only stop early where they check the token, so others finish in the background,
+    // holding their slot until they do.
+    let run = match receiver.recv_timeout(timeout) {
+        Ok(run) => run,
+        Err(_) => {
+            cancel.cancel();
+            return Response::error(504, format!("Solving took longer than {:?}", timeout));
+        }
+    };
+
+    let elapsed_ns = u64::try_from(run.elapsed.as_nanos()).unwrap_or(u64::MAX);
+    match run.answer {
+        Ok(answer) => Response::json(200, &SolveResponse {
+            day: day.day,
+            part,
+            answer: Some(answer),
+            elapsed_ns,
+            diagnostics: None,
+        }),
+        Err(e) => Response::json(422, &SolveResponse {
+            day: day.day,
+            part,
+            answer: None,
+            elapsed_ns,
+            diagnostics: Some(e.to_string()),
+        }),
+    }
+}
+
+/// Routes a request. `body` is `None` when it was larger than the configured limit.
+pub fn handle(method: &str, url: &str, body: Option<String>, config: &ServeConfig, solvers: &Arc<Solvers>) -> Response {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    let (year, route) = match segments.as_slice() {
+        ["years", year, route @ ..] => match year.parse() {
+            Ok(year) => (year, route),
+            Err(_) => return Response::error(404, format!("No route for {}", path)),
+        },
+        route => (config.year, route),
+    };
+    if registry::days(year).is_empty() {
+        return Response::error(404, format!("Year {} is not registered", year));
+    }
+
+    match route {
+        ["days"] => match method {
+            "GET" => list_days(registry::days(year)),
+            _ => Response::error(405, "Only GET is supported"),
+        },
+        ["days", day, "parts", part] => {
+            if method != "POST" {
+                return Response::error(405, "Only POST is supported");
+            }
+
+            let Some(day) = day.parse().ok().and_then(|x| registry::day(year, x)) else {
+                return Response::error(404, format!("Day {} is not registered", day));
+            };
+            let Some(part) = part.parse::<u8>().ok().filter(|x| (1..=day.parts.len() as u8).contains(x)) else {
+                return Response::error(404, format!("Day {} has no part {}", day.day, part));
+            };
+            let Some(input) = body else {
+                return Response::error(413, format!("Input is larger than {} bytes", config.max_body_bytes));
+            };
+
+            match parse_query(query) {
+                Ok(overrides) => solve_with_timeout(day, part, input, &overrides, config.timeout, solvers),
+                Err(e) => Response::error(400, e.to_string()),
+            }
+        }
+        _ => Response::error(404, format!("No route for {}", path)),
+    }
+}
+
+fn respond(mut request: Request, config: &ServeConfig, solvers: &Arc<Solvers>) {
+    let limit = config.max_body_bytes;
+    let too_large = request.body_length().is_some_and(|x| x > limit);
+
+    let body = match too_large {
+        true => Ok(None),
+        false => {
+            let mut bytes = Vec::new();
+            request
+                .as_reader()
+                .take(limit as u64 + 1)
+                .read_to_end(&mut bytes)
+                .map(|_| match bytes.len() > limit {
+                    true => None,
+                    false => Some(bytes),
+                })
+        }
+    };
+
+    let response = match body {
+        Ok(Some(bytes)) => match String::from_utf8(bytes) {
+            Ok(body) => handle(request.method().as_str(), request.url(), Some(body), config, solvers),
+            Err(_) => Response::error(400, "Input is not valid UTF-8"),
+        },
+        Ok(None) => handle(request.method().as_str(), request.url(), None, config, solvers),
+        Err(e) => Response::error(400, e.to_string()),
+    };
+
+    let header = Header::from_bytes("Content-Type", "application/json").expect("Header should be valid");
+    let _ = request.respond(
+        tiny_http::Response::from_string(response.body)
+            .with_status_code(response.status)
+            .with_header(header),
+    );
+}
+
+/// Answers requests on `server` until it shuts down, `workers` at a time. Later requests wait
+/// for a worker to be free.
+pub fn run(server: Server, config: ServeConfig) {
+    let solvers = Solvers::new(config.workers);
+
+    thread::scope(|scope| {
+        for _ in 0..config.workers.max(1) {
+            scope.spawn(|| {
+                for request in server.incoming_requests() {
+                    respond(request, &config, &solvers);
+                }
+            });
+        }
+    });
+}
+
+pub fn serve(address: &str, config: ServeConfig) -> eyre::Result<()> {
+    let server = Server::http(address).map_err(|e| eyre!("Could not listen on {}: {}", address, e))?;
+    println!("Listening on http://{}", server.server_addr());
+
+    run(server, config);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Part;
+    use std::io::Write;
+    use std::net::TcpStream;
+    use std::sync::atomic::AtomicBool;
+
+    static RELEASED: AtomicBool = AtomicBool::new(false);
+
+    /// Part 1 ignores its token and runs until released, and part 2 finishes straight away.
+    static STUBBORN: Day = Day {
+        year: 2024,
+        day: 1,
+        parameters: &[],
+        parts: [
+            Part::Solved(|_, _, _| {
+                while !RELEASED.load(Ordering::Acquire) {
+                    thread::yield_now();
+                }
+                Ok(Answer::from(1))
+            }),
+            Part::Solved(|_, _, _| Ok(Answer::from(2))),
+        ],
+    };
+
+    const CONFIG: ServeConfig = ServeConfig {
+        year: 2024,
+        max_body_bytes: 64,
+        timeout: Duration::from_secs(5),
+        workers: 2,
+    };
+
+    #[test]
+    fn should_list_days_with_parameters() {
+        // When
+        let response = handle("GET", "/days", Some(String::new()), &CONFIG, &Solvers::new(2));
+
+        // Then
+        assert_eq!(200, response.status);
+        assert!(response.body.contains(
+            r#"{"day":14,"parts":2,"parameters":[{"name":"width","default":101},{"name":"height","default":103}]}"#
+        ));
+    }
+
+    #[test]
+    fn should_solve_posted_input() {
+        // Given
+        let input = "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2\np=2,0 v=2,-1\np=0,0 v=1,3\np=3,0 v=-2,-2\np=7,6 v=-1,-3\np=3,0 v=-1,-2\np=9,3 v=2,3\np=7,3 v=-1,2\np=2,4 v=2,-3\np=9,5 v=-3,-3";
+        let config = ServeConfig { max_body_bytes: 1024, ..CONFIG };
+
+        // When
+        let response = handle("POST", "/days/14/parts/1?width=11&height=7", Some(input.to_string()), &config, &Solvers::new(2));
+
+        // Then
+        assert_eq!(200, response.status);
+        assert!(response.body.starts_with(r#"{"day":14,"part":1,"answer":12,"#));
+    }
+
+    #[test]
+    fn should_report_parse_diagnostics() {
+        // When
+        let response = handle("POST", "/days/7/parts/1", Some("190 10 19".to_string()), &CONFIG, &Solvers::new(2));
+
+        // Then
+        assert_eq!(422, response.status);
+        assert!(response.body.contains(r#""diagnostics":"Could not parse input: 1:1: missing separator"#));
+    }
+
+    #[test]
+    fn should_reject_oversized_and_unknown_requests() {
+        // Given
+        let solvers = Solvers::new(2);
+
+        // When
+        let too_large = handle("POST", "/days/1/parts/1", None, &CONFIG, &solvers);
+        let unknown_day = handle("POST", "/days/25/parts/1", Some(String::new()), &CONFIG, &solvers);
+        let unknown_part = handle("POST", "/days/1/parts/3", Some(String::new()), &CONFIG, &solvers);
+        let unknown_year = handle("GET", "/years/2015/days", None, &CONFIG, &solvers);
+
+        // Then
+        assert_eq!(413, too_large.status);
+        assert_eq!(404, unknown_day.status);
+        assert_eq!(404, unknown_part.status);
+        assert_eq!(404, unknown_year.status);
+    }
+
+    #[test]
+    fn should_solve_days_of_a_named_year() {
+        // Given
+        let input = "3 4\n4 3\n2 5\n1 3\n3 9\n3 3";
+        let config = ServeConfig { year: 2015, ..CONFIG };
+
+        // When
+        let response = handle("POST", "/years/2024/days/1/parts/2", Some(input.to_string()), &config, &Solvers::new(2));
+
+        // Then
+        assert_eq!(200, response.status);
+        assert!(response.body.contains(r#""answer":31"#));
+    }
+
+    #[test]
+    fn should_refuse_solves_while_timed_out_solvers_still_run() {
+        // Given
+        let solvers = Solvers::new(1);
+        let timeout = Duration::from_millis(20);
+
+        // When
+        let timed_out = solve_with_timeout(&STUBBORN, 1, String::new(), &[], timeout, &solvers);
+        let refused = solve_with_timeout(&STUBBORN, 2, String::new(), &[], timeout, &solvers);
+        RELEASED.store(true, Ordering::Release);
+        while solvers.running.load(Ordering::Acquire) > 0 {
+            thread::yield_now();
+        }
+        let solved = solve_with_timeout(&STUBBORN, 2, String::new(), &[], timeout, &solvers);
+
+        // Then
+        assert_eq!(504, timed_out.status);
+        assert_eq!(503, refused.status);
+        assert_eq!(200, solved.status);
+    }
+
+    #[test]
+    fn should_serve_over_localhost() {
+        // Given
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let address = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || run(server, CONFIG));
+        let body = "3 4\n4 3\n2 5\n1 3\n3 9\n3 3";
+
+        // When
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "POST /days/1/parts/2 HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        // Then
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains(r#""answer":31"#));
+    }
+}
@@ -0,0 +1,230 @@
+use crate::answer_book::AnswerBook;
+use crate::registry;
+use crate::workspace::Workspace;
+use eyre::{eyre, WrapErr};
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter, Write};
+use std::fs;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PartStatus {
+    Missing,
+    Unsolved,
+    Solved,
+}
+
+#[derive(Debug)]
+pub struct DayStatus {
+    pub day: u8,
+    pub has_crate: bool,
+    pub parts: [PartStatus; 2],
+    pub accepted: [bool; 2],
+    pub fixtures: usize,
+    pub has_bench: bool,
+}
+
+impl DayStatus {
+    fn collect(workspace: &Workspace, year: u16, day: u8) -> eyre::Result<DayStatus> {
+        let parts = match registry::day(year, day) {
+            Some(registered) => registered.parts.map(|x| match x.is_solved() {
+                true => PartStatus::Solved,
+                false => PartStatus::Unsolved,
+            }),
+            None => [PartStatus::Missing; 2],
+        };
+
+        let answer_book = AnswerBook::load(&workspace.answer_book_path(year, day))?;
+        let fixtures = fs::read_dir(workspace.fixtures_dir(year, day))
+            .map(|x| x.filter_map(|x| x.ok()).filter(|x| x.path().is_file()).count())
+            .unwrap_or(0);
+
+        Ok(DayStatus {
+            day,
+            has_crate: workspace.day_dir(year, day).join("Cargo.toml").exists(),
+            parts,
+            accepted: [1, 2].map(|part| answer_book.get(part).is_some()),
+            fixtures,
+            has_bench: workspace.day_dir(year, day).join("benches").join("bench.rs").exists(),
+        })
+    }
+}
+
+pub fn collect(workspace: &Workspace, year: u16) -> eyre::Result<Vec<DayStatus>> {
+    (1..=25).map(|day| DayStatus::collect(workspace, year, day)).collect()
+}
+
+pub fn calendar(statuses: &[DayStatus]) -> String {
+    let yes_no = |x: bool| if x { "yes" } else { "-" };
+    let part = |x: PartStatus| match x {
+        PartStatus::Missing => "-",
+        PartStatus::Unsolved => "unsolved",
+        PartStatus::Solved => "solved",
+    };
+
+    let mut calendar = format!(
+        "{:>3}  {:<5}  {:<8}  {:<8}  {:<8}  {:>8}  {}\n",
+        "Day", "Crate", "Part 1", "Part 2", "Accepted", "Fixtures", "Bench"
+    );
+
+    for status in statuses {
+        let accepted = [1, 2]
+            .iter()
+            .zip(status.accepted)
+            .filter(|(_, accepted)| *accepted)
+            .map(|(part, _)| part.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let _ = writeln!(
+            calendar,
+            "{:>3}  {:<5}  {:<8}  {:<8}  {:<8}  {:>8}  {}",
+            status.day,
+            yes_no(status.has_crate),
+            part(status.parts[0]),
+            part(status.parts[1]),
+            if accepted.is_empty() { "-" } else { &accepted },
+            status.fixtures,
+            yes_no(status.has_bench),
+        );
+    }
+
+    let solved = Progress::from(statuses).solved.len();
+    let _ = writeln!(calendar, "\n{}/50 parts solved", solved);
+
+    calendar
+}
+
+/// The parts recorded as solved, stored as `day_01: part_1, part_2` lines in the year's
+/// `progress.txt` so that a check can tell when a part regresses to unsolved.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Progress {
+    solved: BTreeSet<(u8, u8)>,
+}
+
+impl From<&[DayStatus]> for Progress {
+    fn from(statuses: &[DayStatus]) -> Self {
+        let solved = statuses
+            .iter()
+            .flat_map(|status| {
+                (1..=2)
+                    .zip(status.parts)
+                    .filter(|(_, x)| *x == PartStatus::Solved)
+                    .map(|(part, _)| (status.day, part))
+            })
+            .collect();
+
+        Progress { solved }
+    }
+}
+
+impl Progress {
+    pub fn load(workspace: &Workspace, year: u16) -> eyre::Result<Progress> {
+        let path = workspace.progress_path(year);
+        fs::read_to_string(&path)
+            .wrap_err_with(|| format!("Could not read {}", path.display()))?
+            .parse()
+    }
+
+    pub fn save(&self, workspace: &Workspace, year: u16) -> eyre::Result<()> {
+        let path = workspace.progress_path(year);
+        fs::write(&path, self.to_string()).wrap_err_with(|| format!("Could not write {}", path.display()))
+    }
+
+    /// Parts recorded as solved that `current` no longer solves.
+    pub fn regressions(&self, current: &Progress) -> Vec<(u8, u8)> {
+        self.solved.difference(&current.solved).copied().collect()
+    }
+}
+
+impl FromStr for Progress {
+    type Err = eyre::Report;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut solved = BTreeSet::new();
+
+        for line in input.lines().filter(|x| !x.trim().is_empty()) {
+            let (day, parts) = line
+                .split_once(':')
+                .ok_or_else(|| eyre!("Progress line {:?} is missing ':'", line))?;
+            let day = day
+                .trim()
+                .strip_prefix("day_")
+                .and_then(|x| x.parse::<u8>().ok())
+                .ok_or_else(|| eyre!("Progress line {:?} has no day", line))?;
+
+            for part in parts.split(',').map(|x| x.trim()).filter(|x| !x.is_empty()) {
+                let part = part
+                    .strip_prefix("part_")
+                    .and_then(|x| x.parse::<u8>().ok())
+                    .ok_or_else(|| eyre!("Progress line {:?} has an invalid part {:?}", line, part))?;
+                solved.insert((day, part));
+            }
+        }
+
+        Ok(Progress { solved })
+    }
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut days = self.solved.iter().map(|(day, _)| *day).collect::<Vec<_>>();
+        days.dedup();
+
+        for day in days {
+            let parts = self
+                .solved
+                .iter()
+                .filter(|(x, _)| *x == day)
+                .map(|(_, part)| format!("part_{}", part))
+                .collect::<Vec<_>>();
+
+            writeln!(f, "day_{:02}: {}", day, parts.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_mark_registered_unsolved_parts() {
+        // When
+        let statuses = collect(&Workspace::default(), 2024).unwrap();
+        let unregistered = collect(&Workspace::default(), 2015).unwrap();
+
+        // Then
+        assert_eq!([PartStatus::Solved, PartStatus::Unsolved], statuses[13].parts);
+        assert_eq!([PartStatus::Missing, PartStatus::Missing], statuses[24].parts);
+        assert!(statuses[0].has_crate && statuses[0].has_bench);
+        assert!(unregistered.iter().all(|x| !x.has_crate && x.parts == [PartStatus::Missing; 2]));
+    }
+
+    #[test]
+    fn should_detect_regressions() {
+        // Given
+        let recorded = Progress::from_str("day_13: part_1, part_2\nday_14: part_1, part_2\n").unwrap();
+        let current = Progress::from_str("day_13: part_1, part_2\nday_14: part_1\n").unwrap();
+
+        // When
+        let regressions = recorded.regressions(&current);
+
+        // Then
+        assert_eq!(vec![(14, 2)], regressions);
+    }
+
+    #[test]
+    fn should_round_trip_progress() {
+        // Given
+        let input = "day_01: part_1, part_2\nday_14: part_1\n";
+
+        // When
+        let progress = Progress::from_str(input).unwrap();
+
+        // Then
+        assert_eq!(input, progress.to_string());
+    }
+}
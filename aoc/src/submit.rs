@@ -0,0 +1,137 @@
+use crate::answer_book::{AnswerBook, Verdict};
+use crate::html;
+use crate::http;
+use answer::Answer;
+use eyre::eyre;
+
+/// What Advent of Code made of a submitted answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    /// Wrong, with the site's hint such as "your answer is too high" when it gave one.
+    Incorrect { hint: Option<String> },
+    /// Submitted too soon after a wrong answer, with how long is left to wait.
+    TooSoon { wait: Option<String> },
+    /// The part is already solved, or part 1 isn't yet.
+    WrongLevel,
+    Unrecognised(String),
+}
+
+/// Reads the outcome from the `<article>` of the page returned after submitting.
+pub fn outcome(page: &str) -> Outcome {
+    let root = html::parse(page);
+    let text = root
+        .find_all("article")
+        .first()
+        .map(|x| x.text())
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let sentence_with = |word: &str| {
+        text.split_terminator(['.', ';'])
+            .map(|x| x.trim())
+            .find(|x| x.contains(word))
+            .map(|x| x.to_string())
+    };
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        Outcome::Incorrect {
+            hint: sentence_with("too high").or_else(|| sentence_with("too low")),
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::TooSoon {
+            wait: sentence_with("left to wait"),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unrecognised(text)
+    }
+}
+
+/// Refuses answers the answer book already settles: resubmitting an accepted answer only costs
+/// time, and anything else is known to be wrong.
+pub fn check_before_submitting(answer_book: &AnswerBook, part: u8, answer: &Answer) -> eyre::Result<()> {
+    match answer_book.check(part, answer) {
+        Verdict::Correct => Err(eyre!("Part {} was already accepted with {}", part, answer)),
+        Verdict::Incorrect { expected } => Err(eyre!("The answer book has {} for part {}, not {}", expected, part, answer)),
+        Verdict::Unknown if answer.to_string().trim().is_empty() => Err(eyre!("Refusing to submit an empty answer")),
+        Verdict::Unknown => Ok(()),
+    }
+}
+
+pub fn submit(base_url: &str, year: u16, day: u8, part: u8, answer: &Answer, session: &str) -> eyre::Result<Outcome> {
+    let url = format!("{}/{}/day/{}/answer", base_url.trim_end_matches('/'), year, day);
+    let page = http::post_form(&url, session, &[("level", &part.to_string()), ("answer", &answer.to_string())])?;
+
+    Ok(outcome(&page))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use tiny_http::{Response, Server};
+
+    #[test]
+    fn should_read_outcomes() {
+        // Given
+        let page = |text: &str| format!("<html><body><main><article><p>{}</p></article></main></body></html>", text);
+
+        // When
+        let correct = outcome(&page("That's the right answer! You are <em>one gold star</em> closer."));
+        let incorrect = outcome(&page("That's not the right answer; your answer is too high. Please wait one minute."));
+        let too_soon = outcome(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 37s left to wait."));
+
+        // Then
+        assert_eq!(Outcome::Correct, correct);
+        assert_eq!(Outcome::Incorrect { hint: Some("your answer is too high".to_string()) }, incorrect);
+        assert_eq!(Outcome::TooSoon { wait: Some("You have 37s left to wait".to_string()) }, too_soon);
+    }
+
+    #[test]
+    fn should_refuse_answers_the_book_settles() {
+        // Given
+        let mut answer_book = AnswerBook::default();
+        answer_book.set(1, 11);
+
+        // When
+        let accepted = check_before_submitting(&answer_book, 1, &Answer::from(11));
+        let wrong = check_before_submitting(&answer_book, 1, &Answer::from(12));
+        let unknown = check_before_submitting(&answer_book, 2, &Answer::from(31));
+
+        // Then
+        assert!(accepted.is_err());
+        assert!(wrong.is_err());
+        assert!(unknown.is_ok());
+    }
+
+    #[test]
+    fn should_post_answer_as_form() {
+        // Given
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr());
+        thread::spawn(move || {
+            let mut request = server.recv().unwrap();
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let text = match (request.url(), body.as_str()) {
+                ("/2024/day/10/answer", "level=2&answer=HER") => "That's the right answer!",
+                _ => "Unexpected",
+            };
+            request
+                .respond(Response::from_string(format!("<article><p>{}</p></article>", text)))
+                .unwrap();
+        });
+
+        // When
+        let outcome = submit(&base_url, 2024, 10, 2, &Answer::Letters("HER".to_string()), "abc").unwrap();
+
+        // Then
+        assert_eq!(Outcome::Correct, outcome);
+    }
+}
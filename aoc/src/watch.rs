@@ -0,0 +1,332 @@
+use crate::answer_book::{AnswerBook, Verdict};
+use crate::escape;
+use crate::registry::PartRun;
+use crate::workspace::Workspace;
+use answer::Answer;
+use eyre::{eyre, WrapErr};
+use inotify::{EventMask, Inotify, WatchDescriptor, WatchMask};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// A part's result as reported by `aoc run --porcelain`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartSummary {
+    pub part: u8,
+    pub answer: Result<Answer, String>,
+    pub elapsed: Duration,
+}
+
+/// Formats a run as a tab separated `part, nanoseconds, ok|err, text` line with newlines escaped.
+pub fn porcelain(run: &PartRun) -> String {
+    let (status, text) = match &run.answer {
+        Ok(answer) => ("ok", answer.to_string()),
+        Err(e) => ("err", e.to_string()),
+    };
+
+    format!(
+        "{}\t{}\t{}\t{}",
+        run.part,
+        run.elapsed.as_nanos(),
+        status,
+        escape::escape(&text)
+    )
+}
+
+pub fn parse_porcelain(line: &str) -> Option<PartSummary> {
+    let mut fields = line.splitn(4, '\t');
+    let part = fields.next()?.parse().ok()?;
+    let elapsed = Duration::from_nanos(fields.next()?.parse().ok()?);
+    let status = fields.next()?;
+    let text = escape::unescape(fields.next()?);
+
+    let answer = match status {
+        "ok" => Ok(text.parse().unwrap_or_else(|x| match x {})),
+        "err" => Err(text),
+        _ => return None,
+    };
+
+    Some(PartSummary { part, answer, elapsed })
+}
+
+/// Describes how a part's answer compares to the previous run and the answer book.
+pub fn describe(summary: &PartSummary, previous: Option<&Answer>, answer_book: &AnswerBook) -> String {
+    let answer = match &summary.answer {
+        Ok(answer) => answer,
+        Err(e) => return format!("Part {}: error: {} ({:.2?})", summary.part, e, summary.elapsed),
+    };
+
+    let change = match previous {
+        Some(previous) if previous != answer => format!(" (was {})", previous),
+        _ => String::new(),
+    };
+
+    let verdict = match answer_book.check(summary.part, answer) {
+        Verdict::Correct => " [matches answer book]".to_string(),
+        Verdict::Incorrect { expected } => format!(" [answer book has {}]", expected),
+        Verdict::Unknown => String::new(),
+    };
+
+    format!(
+        "Part {}: {}{}{} ({:.2?})",
+        summary.part, answer, change, verdict, summary.elapsed
+    )
+}
+
+/// Blocks until a change arrives, then keeps collecting changes until none arrive for `quiet`.
+/// Returns `None` once the sender has gone away.
+pub fn debounce(changes: &Receiver<PathBuf>, quiet: Duration) -> Option<Vec<PathBuf>> {
+    let mut batch = vec![changes.recv().ok()?];
+
+    loop {
+        match changes.recv_timeout(quiet) {
+            Ok(path) => {
+                if !batch.contains(&path) {
+                    batch.push(path)
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => return Some(batch),
+            Err(RecvTimeoutError::Disconnected) => return Some(batch),
+        }
+    }
+}
+
+fn is_relevant(name: &str) -> bool {
+    !(name.starts_with('.') || name.ends_with('~') || name.ends_with(".swp"))
+}
+
+fn directories(dir: &Path) -> eyre::Result<Vec<PathBuf>> {
+    let mut found = vec![dir.to_path_buf()];
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() && path.file_name().is_some_and(|x| x != "target") {
+            found.append(&mut directories(&path)?);
+        }
+    }
+
+    Ok(found)
+}
+
+/// Watches `dirs`, and any directory later created or moved into them other than `target`.
+fn spawn_watcher(dirs: Vec<PathBuf>) -> eyre::Result<Receiver<PathBuf>> {
+    let mut inotify = Inotify::init().wrap_err("Could not initialise inotify")?;
+    let mask = WatchMask::CLOSE_WRITE | WatchMask::CREATE | WatchMask::DELETE | WatchMask::MOVED_TO;
+
+    let mut watched: HashMap<WatchDescriptor, PathBuf> = dirs
+        .into_iter()
+        .map(|dir| Ok((inotify.watches().add(&dir, mask)?, dir)))
+        .collect::<eyre::Result<_>>()?;
+
+    let (sender, receiver) = channel();
+
+    thread::spawn(move || {
+        let mut buffer = [0; 4096];
+        while let Ok(events) = inotify.read_events_blocking(&mut buffer) {
+            for event in events {
+                let (Some(dir), Some(name)) = (watched.get(&event.wd), event.name) else {
+                    continue;
+                };
+                let dir = dir.clone();
+
+                // Watch the new directory before reporting it, so nothing written inside it after
+                // the report goes unseen. It may already hold directories of its own.
+                let created = event.mask.intersects(EventMask::CREATE | EventMask::MOVED_TO);
+                if created && event.mask.contains(EventMask::ISDIR) && name != "target" {
+                    for new_dir in directories(&dir.join(name)).unwrap_or_default() {
+                        if let Ok(descriptor) = inotify.watches().add(&new_dir, mask) {
+                            watched.insert(descriptor, new_dir);
+                        }
+                    }
+                }
+
+                if is_relevant(&name.to_string_lossy()) && sender.send(dir.join(name)).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+
+    Ok(receiver)
+}
+
+fn run_examples(workspace: &Workspace, year: u16, day: u8) -> eyre::Result<bool> {
+    let status = Command::new("cargo")
+        .args(["test", "-q", "-p", &Workspace::crate_name(year, day)])
+        .current_dir(workspace.root())
+        .status()
+        .wrap_err("Could not run cargo test")?;
+
+    Ok(status.success())
+}
+
+fn run_input(workspace: &Workspace, year: u16, day: u8) -> eyre::Result<Vec<PartSummary>> {
+    let output = Command::new("cargo")
+        .args(["run", "-q", "--release", "-p", "aoc", "--", "--year", &year.to_string()])
+        .args(["run", &day.to_string(), "--porcelain"])
+        .current_dir(workspace.root())
+        .stderr(Stdio::inherit())
+        .output()
+        .wrap_err("Could not run aoc")?;
+
+    if !output.status.success() {
+        return Err(eyre!("aoc run exited with {}", output.status));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(parse_porcelain)
+        .collect())
+}
+
+fn run_cycle(workspace: &Workspace, year: u16, day: u8, previous: &mut BTreeMap<u8, Answer>) -> eyre::Result<()> {
+    match run_examples(workspace, year, day)? {
+        true => println!("Examples: passed"),
+        false => println!("Examples: FAILED"),
+    }
+
+    let input_path = workspace.input_path(year, day);
+    if !input_path.exists() && !vault::vault_path(&input_path).exists() {
+        println!("No input at {}", input_path.display());
+        return Ok(());
+    }
+
+    let answer_book = AnswerBook::load(&workspace.answer_book_path(year, day))?;
+    for summary in run_input(workspace, year, day)? {
+        println!("{}", describe(&summary, previous.get(&summary.part), &answer_book));
+
+        if let Ok(answer) = summary.answer {
+            previous.insert(summary.part, answer);
+        }
+    }
+
+    Ok(())
+}
+
+/// Re-runs a day's example tests and real input whenever a file in the day's crate changes.
+pub fn watch(workspace: &Workspace, year: u16, day: u8, quiet: Duration) -> eyre::Result<()> {
+    let changes = spawn_watcher(directories(&workspace.day_dir(year, day))?)?;
+    let mut previous = BTreeMap::new();
+
+    println!("Watching {}", workspace.day_dir(year, day).display());
+    run_cycle(workspace, year, day, &mut previous)?;
+
+    while let Some(changed) = debounce(&changes, quiet) {
+        let names = changed
+            .iter()
+            .filter_map(|x| x.file_name())
+            .map(|x| x.to_string_lossy())
+            .collect::<Vec<_>>();
+
+        println!("\nChanged: {}", names.join(", "));
+        if let Err(e) = run_cycle(workspace, year, day, &mut previous) {
+            println!("{:?}", e);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_porcelain() {
+        // Given
+        let run = PartRun {
+            part: 2,
+            answer: Ok(Answer::from("HI\nTHERE\\nAGAIN")),
+            elapsed: Duration::from_micros(1500),
+        };
+
+        // When
+        let summary = parse_porcelain(&porcelain(&run)).unwrap();
+
+        // Then
+        assert_eq!(
+            PartSummary {
+                part: 2,
+                answer: Ok(Answer::from("HI\nTHERE\\nAGAIN")),
+                elapsed: Duration::from_micros(1500),
+            },
+            summary
+        );
+    }
+
+    #[test]
+    fn should_describe_changed_answer_against_answer_book() {
+        // Given
+        let summary = PartSummary {
+            part: 1,
+            answer: Ok(Answer::from(41)),
+            elapsed: Duration::from_millis(2),
+        };
+        let mut answer_book = AnswerBook::default();
+        answer_book.set(1, 41);
+
+        // When
+        let description = describe(&summary, Some(&Answer::from(40)), &answer_book);
+
+        // Then
+        assert_eq!("Part 1: 41 (was 40) [matches answer book] (2.00ms)", description);
+    }
+
+    #[test]
+    fn should_debounce_rapid_changes() {
+        // Given
+        let (sender, receiver) = channel();
+        sender.send(PathBuf::from("lib.rs")).unwrap();
+        sender.send(PathBuf::from("lib.rs")).unwrap();
+        sender.send(PathBuf::from("input.txt")).unwrap();
+
+        // When
+        let batch = debounce(&receiver, Duration::from_millis(20));
+
+        // Then
+        assert_eq!(
+            Some(vec![PathBuf::from("lib.rs"), PathBuf::from("input.txt")]),
+            batch
+        );
+    }
+
+    #[test]
+    fn should_notify_of_file_changes() {
+        // Given
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let changes = spawn_watcher(vec![dir.clone()]).unwrap();
+
+        // When
+        fs::write(dir.join("input.txt"), "1 2").unwrap();
+        let batch = debounce(&changes, Duration::from_millis(50));
+
+        // Then
+        assert_eq!(Some(vec![dir.join("input.txt")]), batch);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn should_notify_of_file_changes_in_new_directories() {
+        // Given
+        let dir = std::env::temp_dir().join(format!("aoc-watch-new-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let changes = spawn_watcher(vec![dir.clone()]).unwrap();
+        fs::create_dir(dir.join("src")).unwrap();
+        fs::create_dir(dir.join("target")).unwrap();
+        debounce(&changes, Duration::from_millis(50));
+
+        // When
+        fs::write(dir.join("target").join("build.rs"), "").unwrap();
+        fs::write(dir.join("src").join("lib.rs"), "").unwrap();
+        let batch = debounce(&changes, Duration::from_millis(50));
+
+        // Then
+        assert_eq!(Some(vec![dir.join("src").join("lib.rs")]), batch);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,87 @@
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    root: PathBuf,
+}
+
+impl Default for Workspace {
+    fn default() -> Self {
+        Workspace::new(Path::new(env!("CARGO_MANIFEST_DIR")).join(".."))
+    }
+}
+
+impl Workspace {
+    pub fn new(root: impl Into<PathBuf>) -> Workspace {
+        Workspace { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn crate_name(year: u16, day: u8) -> String {
+        format!("y{}_day_{:02}", year, day)
+    }
+
+    /// The directory holding a year's day crates, progress and budgets.
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string())
+    }
+
+    pub fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.year_dir(year).join(format!("day_{:02}", day))
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("input.txt")
+    }
+
+    pub fn answer_book_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("answers.txt")
+    }
+
+    pub fn fixtures_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("fixtures")
+    }
+
+    pub fn progress_path(&self, year: u16) -> PathBuf {
+        self.year_dir(year).join("progress.txt")
+    }
+
+    pub fn budgets_path(&self, year: u16) -> PathBuf {
+        self.year_dir(year).join("budgets.txt")
+    }
+
+    /// The runner's glue between days and their solvers, `registry.rs` and a file per year.
+    pub fn registry_sources(&self) -> [PathBuf; 2] {
+        let src = self.root.join("aoc").join("src");
+
+        [src.join("registry.rs"), src.join("registry")]
+    }
+
+    pub fn cache_path(&self) -> PathBuf {
+        self.root.join("target").join("answer-cache.txt")
+    }
+
+    /// Lines typed into `aoc repl`, kept between sessions.
+    pub fn repl_history_path(&self) -> PathBuf {
+        self.root.join("target").join("repl-history.txt")
+    }
+
+    /// The external solvers `aoc compare` runs alongside the Rust ones.
+    pub fn solvers_path(&self) -> PathBuf {
+        self.root.join("solvers.txt")
+    }
+
+    /// Reads a day's input, mapping the plaintext into memory or decrypting it from the vault
+    /// when there is no plaintext copy.
+    pub fn read_input(&self, year: u16, day: u8) -> eyre::Result<vault::Input> {
+        vault::read_input(self.day_dir(year, day))
+    }
+
+    /// The files kept encrypted in the vault for a day.
+    pub fn vault_files(&self, year: u16, day: u8) -> [PathBuf; 2] {
+        [self.input_path(year, day), self.answer_book_path(year, day)]
+    }
+}
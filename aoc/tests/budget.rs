@@ -0,0 +1,52 @@
+use aoc::budget::{Budgets, Timing};
+use aoc::registry;
+use aoc::workspace::Workspace;
+
+/// Solves every registered day on its real input and checks the times against its year's
+/// `budgets.txt`. Budgets are only enforced in release builds:
+/// `cargo test --release -p aoc --test budget`.
+#[test]
+fn should_solve_every_day_within_budget() {
+    // Given
+    let workspace = Workspace::default();
+    let mut breakdowns = Vec::new();
+    let mut over_budget = false;
+
+    for (year, days) in &registry::YEARS {
+        let budgets = Budgets::load(&workspace, *year).unwrap();
+        let mut timings = Vec::new();
+
+        // When
+        for day in days.iter() {
+            let input_path = workspace.input_path(*year, day.day);
+            if !input_path.exists() && !vault::vault_path(&input_path).exists() {
+                println!("{} day {}: skipped, no input", year, day.day);
+                continue;
+            }
+
+            let input = workspace.read_input(*year, day.day).unwrap();
+            let arguments = day.arguments(&[]).unwrap();
+            for (part, _) in (1..=2).zip(day.parts).filter(|(_, x)| x.is_solved()) {
+                let run = day.solve(part, &input, &arguments);
+                run.answer.unwrap();
+                timings.push(Timing {
+                    day: day.day,
+                    part,
+                    elapsed: run.elapsed,
+                });
+            }
+        }
+
+        let overruns = budgets.check(&timings);
+        over_budget |= !overruns.is_empty();
+        breakdowns.push(format!("{}\n{}", year, budgets.breakdown(&timings, &overruns)));
+    }
+
+    // Then
+    let breakdown = breakdowns.join("\n");
+    if cfg!(debug_assertions) {
+        println!("Budgets are only enforced in release builds\n{}", breakdown);
+    } else {
+        assert!(!over_budget, "Over budget:\n{}", breakdown);
+    }
+}
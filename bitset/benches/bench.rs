@@ -0,0 +1,50 @@
+use bitset::{PointSet, VisitedMap};
+use std::collections::HashSet;
+
+const SIZE: usize = 130;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+/// A walk over a grid the size of a day 06 map that revisits most points several times.
+fn walk() -> impl Iterator<Item = (usize, usize)> {
+    (0..SIZE * SIZE * 4).map(|i| ((i * 7) % SIZE, (i * 3 / 2) % SIZE))
+}
+
+#[divan::bench]
+fn hash_set() -> usize {
+    let mut visited = HashSet::new();
+    for point in walk() {
+        visited.insert(divan::black_box(point));
+    }
+    visited.len()
+}
+
+#[divan::bench]
+fn point_set() -> usize {
+    let mut visited = PointSet::new(SIZE, SIZE);
+    for (x, y) in walk() {
+        visited.insert(divan::black_box(x), y);
+    }
+    visited.len()
+}
+
+#[divan::bench]
+fn hash_set_with_direction() -> usize {
+    let mut visited = HashSet::new();
+    walk()
+        .enumerate()
+        .filter(|&(i, point)| visited.insert(divan::black_box((point, i % 4))))
+        .count()
+}
+
+#[divan::bench]
+fn visited_map() -> usize {
+    let mut visited = VisitedMap::new(SIZE, SIZE, 4);
+    walk()
+        .enumerate()
+        .filter(|&(i, (x, y))| visited.insert(divan::black_box(x), y, i % 4))
+        .count()
+}
@@ -0,0 +1,5 @@
+mod point_set;
+mod visited_map;
+
+pub use point_set::PointSet;
+pub use visited_map::VisitedMap;
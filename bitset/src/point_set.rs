@@ -0,0 +1,230 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::{BitAnd, BitOr, Sub};
+
+pub(crate) const BITS: usize = u64::BITS as usize;
+
+/// Iterates over the positions of the set bits in `words`, lowest first.
+pub(crate) fn set_bits(words: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    words.iter().enumerate().flat_map(|(i, &word)| {
+        let mut word = word;
+        std::iter::from_fn(move || match word {
+            0 => None,
+            _ => {
+                let bit = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(i * BITS + bit)
+            }
+        })
+    })
+}
+
+/// A set of `(x, y)` points on a grid of known size, stored as one bit per cell. Points outside
+/// the grid are never contained, so neighbours can be looked up without checking bounds first.
+#[derive(Clone, Eq, PartialEq)]
+pub struct PointSet {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    len: usize,
+}
+
+impl PointSet {
+    pub fn new(width: usize, height: usize) -> PointSet {
+        PointSet {
+            words: vec![0; (width * height).div_ceil(BITS)],
+            width,
+            height,
+            len: 0,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn cell(&self, x: usize, y: usize) -> Option<usize> {
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        self.cell(x, y)
+            .is_some_and(|i| self.words[i / BITS] & (1 << (i % BITS)) != 0)
+    }
+
+    /// Adds a point, returning whether it wasn't already in the set. Panics if the point is
+    /// outside the grid.
+    pub fn insert(&mut self, x: usize, y: usize) -> bool {
+        let i = self.cell(x, y).unwrap_or_else(|| {
+            panic!("Point ({}, {}) should be inside the {}x{} grid", x, y, self.width, self.height)
+        });
+        let word = &mut self.words[i / BITS];
+        let bit = 1 << (i % BITS);
+        let added = *word & bit == 0;
+
+        *word |= bit;
+        self.len += usize::from(added);
+        added
+    }
+
+    /// Removes a point, returning whether it was in the set.
+    pub fn remove(&mut self, x: usize, y: usize) -> bool {
+        let Some(i) = self.cell(x, y) else {
+            return false;
+        };
+        let word = &mut self.words[i / BITS];
+        let bit = 1 << (i % BITS);
+        let removed = *word & bit != 0;
+
+        *word &= !bit;
+        self.len -= usize::from(removed);
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+        self.len = 0;
+    }
+
+    /// Iterates over the points row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        set_bits(&self.words).map(|i| (i % self.width, i / self.width))
+    }
+
+    fn combine(&mut self, other: &PointSet, op: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            (self.width, self.height),
+            (other.width, other.height),
+            "Point sets should share their grid dimensions"
+        );
+
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word = op(*word, *other);
+        }
+        self.len = self.words.iter().map(|x| x.count_ones() as usize).sum();
+    }
+
+    pub fn union_with(&mut self, other: &PointSet) {
+        self.combine(other, |a, b| a | b);
+    }
+
+    pub fn intersect_with(&mut self, other: &PointSet) {
+        self.combine(other, |a, b| a & b);
+    }
+
+    pub fn difference_with(&mut self, other: &PointSet) {
+        self.combine(other, |a, b| a & !b);
+    }
+
+    pub fn is_subset(&self, other: &PointSet) -> bool {
+        self.len <= other.len && self.words.iter().zip(&other.words).all(|(a, b)| a & !b == 0)
+    }
+}
+
+impl BitOr for &PointSet {
+    type Output = PointSet;
+
+    fn bitor(self, other: &PointSet) -> PointSet {
+        let mut union = self.clone();
+        union.union_with(other);
+        union
+    }
+}
+
+impl BitAnd for &PointSet {
+    type Output = PointSet;
+
+    fn bitand(self, other: &PointSet) -> PointSet {
+        let mut intersection = self.clone();
+        intersection.intersect_with(other);
+        intersection
+    }
+}
+
+impl Sub for &PointSet {
+    type Output = PointSet;
+
+    fn sub(self, other: &PointSet) -> PointSet {
+        let mut difference = self.clone();
+        difference.difference_with(other);
+        difference
+    }
+}
+
+impl Extend<(usize, usize)> for PointSet {
+    fn extend<I: IntoIterator<Item = (usize, usize)>>(&mut self, points: I) {
+        for (x, y) in points {
+            self.insert(x, y);
+        }
+    }
+}
+
+impl Debug for PointSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_insert_remove_and_iterate_points() {
+        // Given
+        let mut points = PointSet::new(10, 10);
+
+        // When
+        let added = [points.insert(9, 0), points.insert(3, 7), points.insert(9, 0)];
+        let removed = [points.remove(3, 7), points.remove(3, 7), points.remove(10, 0)];
+        points.insert(0, 9);
+
+        // Then
+        assert_eq!([true, true, false], added);
+        assert_eq!([true, false, false], removed);
+        assert_eq!(vec![(9, 0), (0, 9)], points.iter().collect::<Vec<_>>());
+        assert_eq!(2, points.len());
+        assert!(!points.contains(10, 0));
+    }
+
+    #[test]
+    fn should_combine_sets() {
+        // Given
+        let mut a = PointSet::new(8, 9);
+        let mut b = PointSet::new(8, 9);
+        a.extend([(0, 0), (1, 1), (7, 8)]);
+        b.extend([(1, 1), (2, 2)]);
+
+        // When
+        let union = &a | &b;
+        let intersection = &a & &b;
+        let difference = &a - &b;
+
+        // Then
+        assert_eq!(4, union.len());
+        assert_eq!(vec![(1, 1)], intersection.iter().collect::<Vec<_>>());
+        assert_eq!(vec![(0, 0), (7, 8)], difference.iter().collect::<Vec<_>>());
+        assert!(intersection.is_subset(&a) && !a.is_subset(&b));
+    }
+
+    #[test]
+    #[should_panic(expected = "inside the 3x3 grid")]
+    fn should_refuse_points_outside_the_grid() {
+        // Given
+        let mut points = PointSet::new(3, 3);
+
+        // When
+        points.insert(3, 0);
+    }
+}
@@ -0,0 +1,106 @@
+use crate::point_set::{set_bits, BITS};
+use crate::PointSet;
+
+/// Which `(x, y)` points on a grid have been visited in which direction, one bit per point and
+/// direction. Walks that must stop when they repeat a step, such as a guard patrolling in a
+/// loop, can check their state with this rather than hashing it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VisitedMap {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    directions: usize,
+}
+
+impl VisitedMap {
+    /// A map for a `width` by `height` grid, with directions numbered from `0` to
+    /// `directions - 1`.
+    pub fn new(width: usize, height: usize, directions: usize) -> VisitedMap {
+        VisitedMap {
+            words: vec![0; (width * height * directions).div_ceil(BITS)],
+            width,
+            height,
+            directions,
+        }
+    }
+
+    fn bit(&self, x: usize, y: usize, direction: usize) -> Option<usize> {
+        assert!(
+            direction < self.directions,
+            "Direction {} should be less than {}",
+            direction,
+            self.directions
+        );
+
+        (x < self.width && y < self.height).then_some((y * self.width + x) * self.directions + direction)
+    }
+
+    pub fn contains(&self, x: usize, y: usize, direction: usize) -> bool {
+        self.bit(x, y, direction)
+            .is_some_and(|i| self.words[i / BITS] & (1 << (i % BITS)) != 0)
+    }
+
+    /// Marks a point as visited in a direction, returning whether it wasn't already. Panics if
+    /// the point is outside the grid.
+    pub fn insert(&mut self, x: usize, y: usize, direction: usize) -> bool {
+        let i = self.bit(x, y, direction).unwrap_or_else(|| {
+            panic!("Point ({}, {}) should be inside the {}x{} grid", x, y, self.width, self.height)
+        });
+        let word = &mut self.words[i / BITS];
+        let bit = 1 << (i % BITS);
+        let added = *word & bit == 0;
+
+        *word |= bit;
+        added
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// The points visited in any direction.
+    pub fn points(&self) -> PointSet {
+        let mut points = PointSet::new(self.width, self.height);
+        points.extend(set_bits(&self.words).map(|i| {
+            let cell = i / self.directions;
+            (cell % self.width, cell / self.width)
+        }));
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_tell_directions_apart() {
+        // Given
+        let mut visited = VisitedMap::new(5, 4, 4);
+
+        // When
+        let first = visited.insert(4, 3, 1);
+        let repeated = visited.insert(4, 3, 1);
+        let turned = visited.insert(4, 3, 2);
+
+        // Then
+        assert!(first && !repeated && turned);
+        assert!(visited.contains(4, 3, 1) && !visited.contains(4, 3, 0));
+        assert!(!visited.contains(5, 3, 1));
+    }
+
+    #[test]
+    fn should_collect_points_visited_in_any_direction() {
+        // Given
+        let mut visited = VisitedMap::new(3, 3, 8);
+        visited.insert(0, 0, 7);
+        visited.insert(0, 0, 2);
+        visited.insert(2, 1, 0);
+
+        // When
+        let points = visited.points();
+
+        // Then
+        assert_eq!(vec![(0, 0), (2, 1)], points.iter().collect::<Vec<_>>());
+    }
+}
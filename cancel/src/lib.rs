@@ -0,0 +1,89 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Asks a long-running solver to stop early. The runner keeps one clone and cancels it when a
+/// part runs out of time, and the solver checks its clone in its longest loops. Checking is a
+/// single relaxed load, so it is cheap enough to do on every step.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+/// Returned by a solver that stopped because its token was cancelled.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Cancelled;
+
+impl CancellationToken {
+    pub fn new() -> CancellationToken {
+        CancellationToken::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Fails once the token is cancelled, for use with `?` inside a loop.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        match self.is_cancelled() {
+            true => Err(Cancelled),
+            false => Ok(()),
+        }
+    }
+}
+
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl Error for Cancelled {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn should_cancel_every_clone() {
+        // Given
+        let token = CancellationToken::new();
+        let clone = token.clone();
+
+        // When
+        let before = clone.check();
+        token.cancel();
+
+        // Then
+        assert_eq!(Ok(()), before);
+        assert_eq!(Err(Cancelled), clone.check());
+        assert!(!CancellationToken::new().is_cancelled());
+    }
+
+    #[test]
+    fn should_stop_a_loop_on_another_thread() {
+        // Given
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        let worker = thread::spawn(move || -> Result<u64, Cancelled> {
+            let mut steps = 0_u64;
+            while steps < u64::MAX {
+                clone.check()?;
+                steps += 1;
+            }
+            Ok(steps)
+        });
+
+        // When
+        token.cancel();
+
+        // Then
+        assert_eq!(Err(Cancelled), worker.join().unwrap());
+    }
+}
@@ -1,13 +0,0 @@
-use std::fs;
-use std::str::FromStr;
-use day_13::Puzzle;
-
-fn main() -> eyre::Result<()> {
-    let input = fs::read_to_string("./input.txt")?;
-
-    let puzzle = Puzzle::from_str(&input).unwrap();
-
-    println!("Part 1: {}", puzzle.part_1());
-
-    Ok(())
-}
\ No newline at end of file
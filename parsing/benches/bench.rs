@@ -0,0 +1,225 @@
+//! Compares the integer scanner with the parsing the days used before it, on generated inputs
+//! about 100 times the size of the real ones.
+
+use parsing::{scan_integers, Segment, Separator};
+use std::sync::LazyLock;
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
+
+/// A small xorshift generator, so the inputs are the same on every run.
+fn numbers(seed: u64) -> impl Iterator<Item = u64> {
+    let mut state = seed;
+    std::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
+
+fn generate(lines: usize, line: impl Fn(&mut dyn Iterator<Item = u64>) -> String) -> String {
+    let mut numbers = numbers(0x2024);
+    (0..lines).map(|_| line(&mut numbers)).collect::<Vec<_>>().join("\n")
+}
+
+static LOCATION_LISTS: LazyLock<String> = LazyLock::new(|| {
+    generate(100_000, |n| format!("{}   {}", 10_000 + n.next().unwrap() % 90_000, 10_000 + n.next().unwrap() % 90_000))
+});
+
+static REPORTS: LazyLock<String> = LazyLock::new(|| {
+    generate(100_000, |n| {
+        let levels = 5 + n.next().unwrap() % 4;
+        (0..levels).map(|_| (1 + n.next().unwrap() % 98).to_string()).collect::<Vec<_>>().join(" ")
+    })
+});
+
+static PAGE_UPDATES: LazyLock<String> = LazyLock::new(|| {
+    generate(20_000, |n| {
+        let pages = 5 + n.next().unwrap() % 19;
+        (0..pages).map(|_| (11 + n.next().unwrap() % 88).to_string()).collect::<Vec<_>>().join(",")
+    })
+});
+
+static EQUATIONS: LazyLock<String> = LazyLock::new(|| {
+    generate(85_000, |n| {
+        let inputs = 3 + n.next().unwrap() % 10;
+        let inputs = (0..inputs).map(|_| (1 + n.next().unwrap() % 999).to_string()).collect::<Vec<_>>();
+        format!("{}: {}", n.next().unwrap() % 1_000_000_000_000, inputs.join(" "))
+    })
+});
+
+static STONES: LazyLock<String> = LazyLock::new(|| generate(1, |n| {
+    (0..800).map(|_| (n.next().unwrap() % 10_000_000).to_string()).collect::<Vec<_>>().join(" ")
+}));
+
+static CLAW_MACHINES: LazyLock<String> = LazyLock::new(|| {
+    generate(32_000, |n| {
+        let mut next = || 10 + n.next().unwrap() % 90;
+        format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            next(),
+            next(),
+            next(),
+            next(),
+            next() * 100,
+            next() * 100
+        )
+    })
+});
+
+static ROBOTS: LazyLock<String> = LazyLock::new(|| {
+    generate(50_000, |n| {
+        let mut next = |range: u64| (n.next().unwrap() % range) as i64;
+        format!("p={},{} v={},{}", next(101), next(103), next(199) - 99, next(199) - 99)
+    })
+});
+
+mod location_lists {
+    use super::*;
+
+    #[divan::bench]
+    fn split_and_parse() -> i32 {
+        divan::black_box(&*LOCATION_LISTS)
+            .lines()
+            .flat_map(|x| x.split(' ').filter_map(|y| y.parse::<i32>().ok()))
+            .sum()
+    }
+
+    #[divan::bench]
+    fn scanner() -> i32 {
+        divan::black_box(&*LOCATION_LISTS).lines().flat_map(scan_integers::<i32>).sum::<Result<_, _>>().unwrap()
+    }
+}
+
+mod reports {
+    use super::*;
+
+    #[divan::bench]
+    fn split_and_parse() -> usize {
+        divan::black_box(&*REPORTS)
+            .lines()
+            .map(|x| x.split_whitespace().filter_map(|y| y.parse::<i32>().ok()).collect::<Vec<_>>())
+            .map(|x| x.len())
+            .sum()
+    }
+
+    #[divan::bench]
+    fn scanner() -> usize {
+        divan::black_box(&*REPORTS)
+            .lines()
+            .map(|x| scan_integers::<i32>(x).collect::<Vec<_>>())
+            .map(|x| x.len())
+            .sum()
+    }
+}
+
+mod page_updates {
+    use super::*;
+
+    #[divan::bench]
+    fn split_and_parse() -> usize {
+        divan::black_box(&*PAGE_UPDATES)
+            .lines()
+            .flat_map(|x| x.split(',').filter_map(|y| y.parse::<usize>().ok()))
+            .sum()
+    }
+
+    #[divan::bench]
+    fn scanner() -> usize {
+        divan::black_box(&*PAGE_UPDATES).lines().flat_map(scan_integers::<usize>).sum::<Result<_, _>>().unwrap()
+    }
+}
+
+mod equations {
+    use super::*;
+
+    #[divan::bench]
+    fn key_values() -> usize {
+        Segment::new(divan::black_box(&*EQUATIONS))
+            .lines()
+            .map(|x| x.key_values::<i64, i64>(":", Separator::Whitespace).unwrap().1.len())
+            .sum()
+    }
+
+    #[divan::bench]
+    fn scanner() -> usize {
+        Segment::new(divan::black_box(&*EQUATIONS))
+            .lines()
+            .map(|x| x.scan_integers::<i64>().collect::<Vec<_>>().len())
+            .sum()
+    }
+}
+
+mod stones {
+    use super::*;
+
+    #[divan::bench]
+    fn split_and_parse() -> u64 {
+        divan::black_box(&*STONES).split(' ').filter_map(|x| x.parse::<u64>().ok()).sum()
+    }
+
+    #[divan::bench]
+    fn scanner() -> u64 {
+        scan_integers::<u64>(divan::black_box(&*STONES)).sum::<Result<_, _>>().unwrap()
+    }
+}
+
+mod claw_machines {
+    use super::*;
+
+    #[divan::bench]
+    fn labelled() -> i64 {
+        Segment::new(divan::black_box(&*CLAW_MACHINES))
+            .blocks()
+            .map(|x| {
+                let (a, rest) = x.split_once("Button B").unwrap();
+                let (b, prize) = rest.split_once("Prize").unwrap();
+                [
+                    a.labelled::<i64>("X+").unwrap(),
+                    a.labelled::<i64>("Y+").unwrap(),
+                    b.labelled::<i64>("X+").unwrap(),
+                    b.labelled::<i64>("Y+").unwrap(),
+                    prize.labelled::<i64>("X=").unwrap(),
+                    prize.labelled::<i64>("Y=").unwrap(),
+                ]
+                .iter()
+                .sum::<i64>()
+            })
+            .sum()
+    }
+
+    #[divan::bench]
+    fn scanner() -> i64 {
+        Segment::new(divan::black_box(&*CLAW_MACHINES))
+            .blocks()
+            .map(|x| x.integer_array::<i64, 6>().unwrap().iter().sum::<i64>())
+            .sum()
+    }
+}
+
+mod robots {
+    use super::*;
+
+    #[divan::bench]
+    fn labelled_pair() -> i32 {
+        Segment::new(divan::black_box(&*ROBOTS))
+            .lines()
+            .map(|line| {
+                let (x, y) = line.labelled_pair::<i32>("p=", ',').unwrap();
+                let (velocity_x, velocity_y) = line.labelled_pair::<i32>("v=", ',').unwrap();
+                x + y + velocity_x + velocity_y
+            })
+            .sum()
+    }
+
+    #[divan::bench]
+    fn scanner() -> i32 {
+        Segment::new(divan::black_box(&*ROBOTS))
+            .lines()
+            .map(|x| x.integer_array::<i32, 4>().unwrap().iter().sum::<i32>())
+            .sum()
+    }
+}
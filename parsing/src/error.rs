@@ -0,0 +1,118 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    /// Returns the 1-based line and column of the start of the span within `source`.
+    pub fn locate(&self, source: &str) -> (usize, usize) {
+        let before = &source[..self.start.min(source.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rfind('\n').map(|i| before.len() - i).unwrap_or(before.len() + 1);
+
+        (line, column)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseErrorKind {
+    InvalidInteger,
+    IntegerOutOfRange,
+    MissingSeparator(String),
+    MissingLabel(String),
+    InvalidCell(char),
+    RaggedGrid { expected: usize, found: usize },
+    IntegerCount { expected: usize, found: usize },
+    Empty,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub span: Span,
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind, span: Span) -> ParseError {
+        ParseError { kind, span }
+    }
+
+    /// Renders the error with its line and column, followed by the offending line and a marker
+    /// underneath the span.
+    pub fn render(&self, source: &str) -> String {
+        let (line, column) = self.span.locate(source);
+        let text = source.lines().nth(line - 1).unwrap_or("");
+        let width = (self.span.end - self.span.start).clamp(1, text.len().saturating_sub(column - 1).max(1));
+
+        format!(
+            "{}:{}: {}\n{}\n{}{}",
+            line,
+            column,
+            self,
+            text,
+            " ".repeat(column - 1),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ParseErrorKind::InvalidInteger => write!(f, "invalid integer"),
+            ParseErrorKind::IntegerOutOfRange => write!(f, "integer out of range"),
+            ParseErrorKind::MissingSeparator(separator) => write!(f, "missing separator {:?}", separator),
+            ParseErrorKind::MissingLabel(label) => write!(f, "missing label {:?}", label),
+            ParseErrorKind::InvalidCell(c) => write!(f, "invalid grid cell {:?}", c),
+            ParseErrorKind::RaggedGrid { expected, found } => {
+                write!(f, "grid row has width {} but expected {}", found, expected)
+            }
+            ParseErrorKind::IntegerCount { expected, found } => {
+                write!(f, "expected {} integers but found {}", expected, found)
+            }
+            ParseErrorKind::Empty => write!(f, "unexpected empty input"),
+        }?;
+
+        write!(f, " at {}..{}", self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_locate_span() {
+        // Given
+        let source = "1 2\n3 x\n";
+        let span = Span::new(6, 7);
+
+        // When
+        let location = span.locate(source);
+
+        // Then
+        assert_eq!((2, 3), location);
+    }
+
+    #[test]
+    fn should_render_error_with_marker() {
+        // Given
+        let source = "1 2\n3 xy\n";
+        let error = ParseError::new(ParseErrorKind::InvalidInteger, Span::new(6, 8));
+
+        // When
+        let rendered = error.render(source);
+
+        // Then
+        assert_eq!("2:3: invalid integer at 6..8\n3 xy\n  ^^", rendered);
+    }
+}
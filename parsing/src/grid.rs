@@ -0,0 +1,62 @@
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(cells: Vec<T>, width: usize, height: usize) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "Grid cells should fill its dimensions");
+
+        Grid { cells, width, height }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        match x < self.width && y < self.height {
+            true => self.cells.get(y * self.width + x),
+            false => None,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over every cell along with its `(x, y)` position.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width.max(1);
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Segment;
+
+    #[test]
+    fn should_index_grid_by_position() {
+        // Given
+        let input = "890\n781";
+
+        // When
+        let grid = Segment::new(input).grid(|c| c.to_digit(10)).unwrap();
+
+        // Then
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(&1), grid.get(2, 1));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(Some(((1, 1), &8)), grid.cells().nth(4));
+    }
+}
@@ -0,0 +1,11 @@
+mod error;
+mod grid;
+mod normalise;
+mod scanner;
+mod segment;
+
+pub use error::{ParseError, ParseErrorKind, Span};
+pub use grid::Grid;
+pub use normalise::normalise;
+pub use scanner::{scan_integers, scan_whitespace_integers, Integer, Integers};
+pub use segment::{Segment, Separator};
@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+
+/// Puts puzzle input into the shape every parser expects, whichever editor or download saved
+/// it: no byte order mark, `\n` line endings, no trailing whitespace on any line and no blank
+/// lines or newline at the end. Input that only needs its ends trimmed, such as a download
+/// ending in a newline, is borrowed rather than copied.
+pub fn normalise(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input).trim_end();
+    if input.split('\n').all(|x| x.trim_end() == x) {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(input.split('\n').map(str::trim_end).collect::<Vec<_>>().join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_normalise_line_endings_bom_and_trailing_whitespace() {
+        // Given
+        let input = "\u{feff}1 2  \r\n\r\n  3 4\t\r\n\r\n\r\n";
+
+        // When
+        let normal = normalise(input);
+
+        // Then
+        assert_eq!("1 2\n\n  3 4", normal);
+    }
+
+    #[test]
+    fn should_borrow_input_that_only_needs_trimming() {
+        // Given
+        let input = "\u{feff}1 2\n\n  3 4\n\n";
+
+        // When
+        let normal = normalise(input);
+
+        // Then
+        assert!(matches!(normal, Cow::Borrowed(_)));
+        assert_eq!("1 2\n\n  3 4", normal);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::error::{ParseError, ParseErrorKind, Span};
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+/// An integer type the scanner can produce.
+pub trait Integer: Copy + Default {
+    /// Whether a `-` directly before the digits makes the number negative.
+    const SIGNED: bool;
+
+    /// Builds the integer from its sign and digits, or `None` if it is out of the type's range.
+    fn from_magnitude(negative: bool, magnitude: u64) -> Option<Self>;
+}
+
+macro_rules! integer {
+    ($signed:literal => $($integer:ty),*) => {
+        $(
+            impl Integer for $integer {
+                const SIGNED: bool = $signed;
+
+                fn from_magnitude(negative: bool, magnitude: u64) -> Option<Self> {
+                    let magnitude = i128::from(magnitude);
+                    let value = match negative {
+                        true => -magnitude,
+                        false => magnitude,
+                    };
+
+                    <$integer>::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+integer!(true => i8, i16, i32, i64, isize);
+integer!(false => u8, u16, u32, u64, usize);
+
+/// The integers in a piece of text, read byte by byte without allocating. Everything other than
+/// digits separates them, so `,`, `|`, `:`, `=`, labels and whitespace all work, and a `-`
+/// directly before digits is a sign when `T` is signed. An integer out of `T`'s range is an
+/// error spanning its digits.
+///
+/// When only whitespace separates them, an item that isn't an integer is an error spanning the
+/// item, and scanning carries on after it.
+#[derive(Debug, Clone)]
+pub struct Integers<'a, T> {
+    bytes: &'a [u8],
+    position: usize,
+    offset: usize,
+    whitespace_only: bool,
+    integer: PhantomData<T>,
+}
+
+pub fn scan_integers<T: Integer>(text: &str) -> Integers<'_, T> {
+    scan_integers_at(text, 0, false)
+}
+
+/// Scans integers separated only by whitespace, such as the levels of a report.
+pub fn scan_whitespace_integers<T: Integer>(text: &str) -> Integers<'_, T> {
+    scan_integers_at(text, 0, true)
+}
+
+/// Scans text that starts `offset` bytes into the input, so errors span the input's bytes.
+pub(crate) fn scan_integers_at<T: Integer>(text: &str, offset: usize, whitespace_only: bool) -> Integers<'_, T> {
+    Integers {
+        bytes: text.as_bytes(),
+        position: 0,
+        offset,
+        whitespace_only,
+        integer: PhantomData,
+    }
+}
+
+impl<T: Integer> Integers<'_, T> {
+    /// Reads the whitespace separated item at the current position, which may be signed.
+    #[inline]
+    fn next_item(&mut self) -> Option<Result<T, ParseError>> {
+        let bytes = self.bytes;
+        let mut i = self.position;
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            self.position = i;
+            return None;
+        }
+
+        let first = i;
+        let negative = T::SIGNED && bytes[i] == b'-';
+        if negative || bytes[i] == b'+' {
+            i += 1;
+        }
+        let digits = i;
+        let magnitude = self.read_digits(&mut i);
+        if i == digits || bytes.get(i).is_some_and(|x| !x.is_ascii_whitespace()) {
+            return Some(Err(self.skip_invalid(first, i)));
+        }
+        self.position = i;
+
+        let span = Span::new(self.offset + first, self.offset + i);
+        Some(
+            magnitude
+                .and_then(|x| T::from_magnitude(negative, x))
+                .ok_or_else(|| ParseError::new(ParseErrorKind::IntegerOutOfRange, span)),
+        )
+    }
+
+    /// Moves past the rest of an item that isn't an integer, for the error spanning it.
+    #[cold]
+    fn skip_invalid(&mut self, first: usize, mut i: usize) -> ParseError {
+        while i < self.bytes.len() && !self.bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        self.position = i;
+
+        ParseError::new(ParseErrorKind::InvalidInteger, Span::new(self.offset + first, self.offset + i))
+    }
+
+    /// Reads the digits from `i` on, or `None` if they overflow a `u64`.
+    #[inline]
+    fn read_digits(&self, i: &mut usize) -> Option<u64> {
+        let mut magnitude = Some(0_u64);
+        while *i < self.bytes.len() && self.bytes[*i].is_ascii_digit() {
+            magnitude = magnitude
+                .and_then(|x| x.checked_mul(10))
+                .and_then(|x| x.checked_add(u64::from(self.bytes[*i] - b'0')));
+            *i += 1;
+        }
+
+        magnitude
+    }
+}
+
+impl<T: Integer> Iterator for Integers<'_, T> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Result<T, ParseError>> {
+        if self.whitespace_only {
+            return self.next_item();
+        }
+
+        let bytes = self.bytes;
+        let start = self.position;
+        let mut i = start;
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            self.position = i;
+            return None;
+        }
+
+        let negative = T::SIGNED && i > start && bytes[i - 1] == b'-';
+        let first = match negative {
+            true => i - 1,
+            false => i,
+        };
+        let magnitude = self.read_digits(&mut i);
+        self.position = i;
+
+        let span = Span::new(self.offset + first, self.offset + i);
+        Some(
+            magnitude
+                .and_then(|x| T::from_magnitude(negative, x))
+                .ok_or_else(|| ParseError::new(ParseErrorKind::IntegerOutOfRange, span)),
+        )
+    }
+}
+
+impl<T: Integer> FusedIterator for Integers<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_scan_integers_between_any_separators() {
+        // Given
+        let input = "Button A: X+94, Y-34\np=0,4 v=3,-3\n47|53\n190: 10 19";
+
+        // When
+        let integers = scan_integers::<i64>(input).collect::<Result<Vec<_>, _>>();
+
+        // Then
+        assert_eq!(Ok(vec![94, -34, 0, 4, 3, -3, 47, 53, 190, 10, 19]), integers);
+    }
+
+    #[test]
+    fn should_ignore_signs_for_unsigned_integers() {
+        // Given
+        let input = "-3,4 18446744073709551615";
+
+        // When
+        let integers = scan_integers::<u64>(input).collect::<Result<Vec<_>, _>>();
+
+        // Then
+        assert_eq!(Ok(vec![3, 4, u64::MAX]), integers);
+        assert_eq!(None, scan_integers::<i32>(" \n-").next());
+    }
+
+    fn out_of_range<T>(start: usize, end: usize) -> Result<T, ParseError> {
+        Err(ParseError::new(ParseErrorKind::IntegerOutOfRange, Span::new(start, end)))
+    }
+
+    fn invalid<T>(start: usize, end: usize) -> Result<T, ParseError> {
+        Err(ParseError::new(ParseErrorKind::InvalidInteger, Span::new(start, end)))
+    }
+
+    #[test]
+    fn should_reject_integers_out_of_range() {
+        // Given
+        let input = "1 -129 128 18446744073709551616 7";
+
+        // When
+        let bytes = scan_integers::<i8>(input).collect::<Vec<_>>();
+        let wide = scan_integers::<u64>(input).collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(vec![Ok(1), out_of_range(2, 6), out_of_range(7, 10), out_of_range(11, 31), Ok(7)], bytes);
+        assert_eq!(out_of_range(11, 31), wide[3]);
+        assert_eq!(Ok(7), wide[4]);
+    }
+
+    #[test]
+    fn should_reject_items_that_are_not_integers_between_whitespace() {
+        // Given
+        let input = "7 +6 -4\n2a x 1,2 - 18446744073709551616 9";
+
+        // When
+        let integers = scan_whitespace_integers::<i64>(input).collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(
+            vec![
+                Ok(7),
+                Ok(6),
+                Ok(-4),
+                invalid(8, 10),
+                invalid(11, 12),
+                invalid(13, 16),
+                invalid(17, 18),
+                out_of_range(19, 39),
+                Ok(9),
+            ],
+            integers
+        );
+        assert_eq!(Some(invalid(0, 2)), scan_whitespace_integers::<u8>("-3").next());
+    }
+}
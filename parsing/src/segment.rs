@@ -0,0 +1,430 @@
+use crate::error::{ParseError, ParseErrorKind, Span};
+use crate::grid::Grid;
+use crate::scanner::{scan_integers_at, Integer, Integers};
+use std::str::FromStr;
+
+/// How the items of a list are separated.
+#[derive(Debug, Clone, Copy)]
+pub enum Separator<'s> {
+    Whitespace,
+    Char(char),
+    Chars(&'s [char]),
+    /// A string, where an empty one separates nothing, like an empty list of `Chars`.
+    Str(&'s str),
+}
+
+/// A slice of the puzzle input that remembers where it starts, so errors can point back into
+/// the original input.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Segment<'a> {
+    pub text: &'a str,
+    pub offset: usize,
+}
+
+impl<'a> From<&'a str> for Segment<'a> {
+    fn from(text: &'a str) -> Self {
+        Segment::new(text)
+    }
+}
+
+impl<'a> Segment<'a> {
+    pub fn new(text: &'a str) -> Segment<'a> {
+        Segment { text, offset: 0 }
+    }
+
+    pub fn span(&self) -> Span {
+        Span::new(self.offset, self.offset + self.text.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(kind, self.span())
+    }
+
+    fn slice(&self, start: usize, end: usize) -> Segment<'a> {
+        Segment {
+            text: &self.text[start..end],
+            offset: self.offset + start,
+        }
+    }
+
+    pub fn trim(&self) -> Segment<'a> {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+
+        self.slice(start, end)
+    }
+
+    /// Splits into lines the same way as [`str::lines`].
+    pub fn lines(&self) -> impl Iterator<Item = Segment<'a>> + 'a {
+        let segment = *self;
+        let mut start = 0;
+
+        std::iter::from_fn(move || {
+            if start >= segment.text.len() {
+                return None;
+            }
+
+            let end = segment.text[start..]
+                .find('\n')
+                .map(|i| start + i)
+                .unwrap_or(segment.text.len());
+            let line = segment.slice(start, end);
+            start = end + 1;
+
+            Some(match line.text.ends_with('\r') {
+                true => line.slice(0, line.text.len() - 1),
+                false => line,
+            })
+        })
+    }
+
+    /// Splits into groups of consecutive non-blank lines.
+    pub fn blocks(&self) -> impl Iterator<Item = Segment<'a>> + 'a {
+        let segment = *self;
+        let mut lines = self.lines().peekable();
+
+        std::iter::from_fn(move || {
+            while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+                last = line;
+            }
+
+            let start = first.offset - segment.offset;
+            let end = last.offset + last.text.len() - segment.offset;
+
+            Some(segment.slice(start, end))
+        })
+    }
+
+    /// Splits into the non-empty items between separators.
+    pub fn split(&self, separator: Separator<'a>) -> impl Iterator<Item = Segment<'a>> + 'a {
+        let segment = *self;
+        let mut start = 0;
+
+        std::iter::from_fn(move || loop {
+            if start > segment.text.len() {
+                return None;
+            }
+
+            let rest = &segment.text[start..];
+            let found = match separator {
+                Separator::Whitespace => rest
+                    .find(char::is_whitespace)
+                    .map(|i| (i, rest[i..].chars().next().map_or(1, char::len_utf8))),
+                Separator::Char(c) => rest.find(c).map(|i| (i, c.len_utf8())),
+                Separator::Chars(chars) => rest
+                    .find(chars)
+                    .map(|i| (i, rest[i..].chars().next().map_or(1, char::len_utf8))),
+                Separator::Str("") => None,
+                Separator::Str(s) => rest.find(s).map(|i| (i, s.len())),
+            };
+
+            let (end, next) = match found {
+                Some((i, width)) => (start + i, start + i + width),
+                None => (segment.text.len(), segment.text.len() + 1),
+            };
+
+            let item = segment.slice(start, end);
+            start = next;
+
+            if !item.is_empty() {
+                return Some(item);
+            }
+        })
+    }
+
+    pub fn split_once(&self, delimiter: &str) -> Result<(Segment<'a>, Segment<'a>), ParseError> {
+        let index = self
+            .text
+            .find(delimiter)
+            .ok_or_else(|| self.error(ParseErrorKind::MissingSeparator(delimiter.to_string())))?;
+
+        Ok((
+            self.slice(0, index),
+            self.slice(index + delimiter.len(), self.text.len()),
+        ))
+    }
+
+    /// Parses the whole (trimmed) segment as an integer.
+    pub fn integer<T: FromStr>(&self) -> Result<T, ParseError> {
+        let trimmed = self.trim();
+        if trimmed.is_empty() {
+            return Err(self.error(ParseErrorKind::Empty));
+        }
+
+        trimmed
+            .text
+            .strip_prefix('+')
+            .unwrap_or(trimmed.text)
+            .parse()
+            .map_err(|_| trimmed.error(ParseErrorKind::InvalidInteger))
+    }
+
+    /// Parses a list of integers such as `7 6 4 2 1` or `75,47,61`.
+    pub fn integers<T: FromStr>(&self, separator: Separator<'a>) -> Result<Vec<T>, ParseError> {
+        self.split(separator).map(|item| item.integer()).collect()
+    }
+
+    /// Scans the integers in the segment, treating anything else as a separator.
+    pub fn scan_integers<T: Integer>(&self) -> Integers<'a, T> {
+        scan_integers_at(self.text, self.offset, false)
+    }
+
+    /// Scans exactly `N` integers, such as the six in a claw machine's configuration.
+    pub fn integer_array<T: Integer, const N: usize>(&self) -> Result<[T; N], ParseError> {
+        let mut integers = self.scan_integers();
+        let mut array = [T::default(); N];
+        let mut found = 0;
+        for (slot, integer) in array.iter_mut().zip(&mut integers) {
+            *slot = integer?;
+            found += 1;
+        }
+        found += integers.count();
+
+        match found == N {
+            true => Ok(array),
+            false => Err(self.error(ParseErrorKind::IntegerCount { expected: N, found })),
+        }
+    }
+
+    /// Parses an `a|b` style pair.
+    pub fn pair<T: FromStr>(&self, delimiter: &str) -> Result<(T, T), ParseError> {
+        let (a, b) = self.split_once(delimiter)?;
+
+        Ok((a.integer()?, b.integer()?))
+    }
+
+    /// Parses a `key: v1 v2 v3` style line.
+    pub fn key_values<K: FromStr, V: FromStr>(
+        &self,
+        delimiter: &str,
+        separator: Separator<'a>,
+    ) -> Result<(K, Vec<V>), ParseError> {
+        let (key, values) = self.split_once(delimiter)?;
+
+        Ok((key.integer()?, values.integers(separator)?))
+    }
+
+    /// Parses the integer following `label`, e.g. `X+` in `Button A: X+94, Y+34`.
+    pub fn labelled<T: FromStr>(&self, label: &str) -> Result<T, ParseError> {
+        self.after(label)?.leading_integer()
+    }
+
+    /// Parses the pair of integers following `label`, e.g. `p=` in `p=0,4 v=3,-3`.
+    pub fn labelled_pair<T: FromStr>(&self, label: &str, separator: char) -> Result<(T, T), ParseError> {
+        let rest = self.after(label)?;
+        let (a, b) = rest.split_once(&separator.to_string())?;
+
+        Ok((a.leading_integer()?, b.leading_integer()?))
+    }
+
+    fn after(&self, label: &str) -> Result<Segment<'a>, ParseError> {
+        let index = self
+            .text
+            .find(label)
+            .ok_or_else(|| self.error(ParseErrorKind::MissingLabel(label.to_string())))?;
+
+        Ok(self.slice(index + label.len(), self.text.len()))
+    }
+
+    fn leading_integer<T: FromStr>(&self) -> Result<T, ParseError> {
+        let bytes = self.text.as_bytes();
+        let sign = match bytes.first() {
+            Some(b'+') | Some(b'-') => 1,
+            _ => 0,
+        };
+        let digits = bytes[sign..].iter().take_while(|b| b.is_ascii_digit()).count();
+
+        let end = match digits {
+            0 => self.text.find(char::is_whitespace).unwrap_or(self.text.len()).max(1),
+            _ => sign + digits,
+        };
+
+        self.slice(0, end.min(self.text.len())).integer()
+    }
+
+    /// Parses a rectangular character grid, mapping each character through `cell`.
+    pub fn grid<T>(&self, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, ParseError> {
+        let mut cells = Vec::with_capacity(self.text.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for line in self.lines() {
+            let mut row_width = 0;
+            for (i, c) in line.text.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    line.slice(i, i + c.len_utf8())
+                        .error(ParseErrorKind::InvalidCell(c))
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+
+            match width {
+                None => width = Some(row_width),
+                Some(expected) if expected != row_width => {
+                    return Err(line.error(ParseErrorKind::RaggedGrid {
+                        expected,
+                        found: row_width,
+                    }))
+                }
+                _ => {}
+            }
+
+            height += 1;
+        }
+
+        match width {
+            Some(width) => Ok(Grid::new(cells, width, height)),
+            None => Err(self.error(ParseErrorKind::Empty)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_integer_lists_with_any_separator() {
+        // Given
+        let spaced = Segment::new("7  6 4 -2 1");
+        let commas = Segment::new("75,47,61");
+
+        // When
+        let spaced = spaced.integers::<i32>(Separator::Whitespace).unwrap();
+        let commas = commas.integers::<usize>(Separator::Char(',')).unwrap();
+
+        // Then
+        assert_eq!(vec![7, 6, 4, -2, 1], spaced);
+        assert_eq!(vec![75, 47, 61], commas);
+    }
+
+    #[test]
+    fn should_not_split_on_empty_separators() {
+        // Given
+        let input = Segment::new("75,47,61");
+
+        // When
+        let by_str = input.split(Separator::Str("")).collect::<Vec<_>>();
+        let by_chars = input.split(Separator::Chars(&[])).collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(vec![input], by_str);
+        assert_eq!(vec![input], by_chars);
+    }
+
+    #[test]
+    fn should_report_span_of_invalid_integer() {
+        // Given
+        let input = Segment::new("1 2\n3 x4 5");
+
+        // When
+        let error = input
+            .lines()
+            .map(|line| line.integers::<u32>(Separator::Whitespace))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+
+        // Then
+        assert_eq!(ParseErrorKind::InvalidInteger, error.kind);
+        assert_eq!(Span::new(6, 8), error.span);
+    }
+
+    #[test]
+    fn should_parse_key_values_and_pairs() {
+        // Given
+        let equation = Segment::new("3267: 81 40 27");
+        let rule = Segment::new("47|53");
+
+        // When
+        let equation = equation.key_values::<i64, i64>(":", Separator::Whitespace).unwrap();
+        let rule = rule.pair::<usize>("|").unwrap();
+
+        // Then
+        assert_eq!((3267, vec![81, 40, 27]), equation);
+        assert_eq!((47, 53), rule);
+    }
+
+    #[test]
+    fn should_scan_a_fixed_number_of_integers() {
+        // Given
+        let robot = Segment::new("p=0,4 v=3,-3");
+        let truncated = Segment { text: "p=0,4 v=3", offset: 13 };
+        let wide = Segment { text: "p=0,4 v=3,-9999999999", offset: 13 };
+
+        // When
+        let robot = robot.integer_array::<i32, 4>().unwrap();
+        let error = truncated.integer_array::<i32, 4>().unwrap_err();
+        let overflow = wide.integer_array::<i32, 4>().unwrap_err();
+
+        // Then
+        assert_eq!([0, 4, 3, -3], robot);
+        assert_eq!(ParseErrorKind::IntegerCount { expected: 4, found: 3 }, error.kind);
+        assert_eq!(Span::new(13, 22), error.span);
+        assert_eq!(ParseError::new(ParseErrorKind::IntegerOutOfRange, Span::new(23, 34)), overflow);
+    }
+
+    #[test]
+    fn should_split_blocks_on_blank_lines() {
+        // Given
+        let input = Segment::new("a\nb\n\n\nc\n\nd\n");
+
+        // When
+        let blocks = input.blocks().collect::<Vec<_>>();
+
+        // Then
+        assert_eq!(vec!["a\nb", "c", "d"], blocks.iter().map(|x| x.text).collect::<Vec<_>>());
+        assert_eq!(6, blocks[1].offset);
+    }
+
+    #[test]
+    fn should_parse_labelled_numbers() {
+        // Given
+        let button = Segment::new("Button A: X+94, Y+34");
+        let robot = Segment::new("p=0,4 v=3,-3");
+
+        // When
+        let x = button.labelled::<i64>("X").unwrap();
+        let y = button.labelled::<i64>("Y").unwrap();
+        let position = robot.labelled_pair::<i32>("p=", ',').unwrap();
+        let velocity = robot.labelled_pair::<i32>("v=", ',').unwrap();
+
+        // Then
+        assert_eq!((94, 34), (x, y));
+        assert_eq!((0, 4), position);
+        assert_eq!((3, -3), velocity);
+    }
+
+    #[test]
+    fn should_report_missing_label() {
+        // Given
+        let button = Segment::new("Button A: X+94");
+
+        // When
+        let error = button.labelled::<i64>("Y").unwrap_err();
+
+        // Then
+        assert_eq!(ParseErrorKind::MissingLabel("Y".to_string()), error.kind);
+    }
+
+    #[test]
+    fn should_reject_ragged_grid() {
+        // Given
+        let input = Segment::new("..#\n.#\n");
+
+        // When
+        let error = input.grid(Some).unwrap_err();
+
+        // Then
+        assert_eq!(ParseErrorKind::RaggedGrid { expected: 3, found: 2 }, error.kind);
+        assert_eq!(Span::new(4, 6), error.span);
+    }
+}
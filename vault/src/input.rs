@@ -0,0 +1,83 @@
+use eyre::{eyre, WrapErr};
+use memmap2::Mmap;
+use std::fmt::{Debug, Formatter};
+use std::fs::{self, File};
+use std::ops::Deref;
+use std::path::Path;
+
+/// Plaintext inputs at least this big are mapped rather than read. Puzzle inputs are tens of
+/// kilobytes, so only generated inputs are ever mapped.
+pub const MAP_THRESHOLD: u64 = 16 * 1024 * 1024;
+
+/// A day's input, mapped straight from a large plaintext file so that it isn't copied, or read
+/// or decrypted into memory.
+pub enum Input {
+    Mapped(Mmap),
+    Owned(String),
+}
+
+impl Input {
+    /// Reads `path`, mapping it into memory when it is at least [`MAP_THRESHOLD`] bytes, and
+    /// checks once that it is UTF-8.
+    pub fn load(path: &Path) -> eyre::Result<Input> {
+        Input::load_mapping_from(path, MAP_THRESHOLD)
+    }
+
+    fn load_mapping_from(path: &Path, threshold: u64) -> eyre::Result<Input> {
+        let file = File::open(path).wrap_err_with(|| format!("Could not open {}", path.display()))?;
+        let length = file.metadata()?.len();
+        if length == 0 || length < threshold {
+            let text = fs::read_to_string(path).wrap_err_with(|| format!("Could not read {}", path.display()))?;
+            return Ok(Input::Owned(text));
+        }
+
+        // SAFETY: the mapping is only sound while nothing truncates or rewrites the file in
+        // place. Only large generated inputs are mapped, which are not edited while being solved
+        // the way watch mode solves puzzle inputs, and this crate replaces files by renaming a
+        // new one over them, which leaves the mapped file as it was.
+        let map = unsafe { Mmap::map(&file) }.wrap_err_with(|| format!("Could not map {}", path.display()))?;
+        std::str::from_utf8(&map).map_err(|_| eyre!("{} is not valid UTF-8", path.display()))?;
+
+        Ok(Input::Mapped(map))
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match self {
+            // SAFETY: checked to be UTF-8 when mapped.
+            Input::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+            Input::Owned(text) => text,
+        }
+    }
+}
+
+impl Debug for Input {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_only_map_inputs_above_the_threshold() {
+        // Given
+        let path = std::env::temp_dir().join(format!("vault-map-{}.txt", std::process::id()));
+        fs::write(&path, "3 4\n").unwrap();
+
+        // When
+        let read = Input::load(&path).unwrap();
+        let mapped = Input::load_mapping_from(&path, 4).unwrap();
+
+        // Then
+        assert!(matches!(read, Input::Owned(_)));
+        assert!(matches!(mapped, Input::Mapped(_)));
+        assert_eq!("3 4\n", &*mapped);
+        fs::remove_file(path).unwrap();
+    }
+}
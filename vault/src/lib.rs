@@ -0,0 +1,322 @@
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use eyre::{eyre, WrapErr};
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+mod input;
+
+pub use input::{Input, MAP_THRESHOLD};
+
+const MAGIC: &[u8] = b"AOCVAULT1";
+const NONCE_LENGTH: usize = 24;
+const TAG_LENGTH: usize = 16;
+pub const KEY_FILE: &str = ".vault-key";
+
+/// The team key, read from `AOC_VAULT_KEY` or the nearest `.vault-key` file as 64 hex digits.
+#[derive(Clone)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn generate() -> Key {
+        Key(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> eyre::Result<Key> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(eyre!("Vault key should be 64 hex digits"));
+        }
+
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+                .map_err(|_| eyre!("Vault key should be 64 hex digits"))?;
+        }
+
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|x| format!("{:02x}", x)).collect()
+    }
+
+    /// Looks for the key in `AOC_VAULT_KEY`, then in `.vault-key` in `dir` or any of its parents.
+    pub fn find(dir: &Path) -> eyre::Result<Key> {
+        if let Ok(hex) = env::var("AOC_VAULT_KEY") {
+            return Key::from_hex(&hex);
+        }
+
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+        dir.ancestors()
+            .map(|x| x.join(KEY_FILE))
+            .find(|x| x.exists())
+            .ok_or_else(|| eyre!("No {} found above {}, and AOC_VAULT_KEY is not set", KEY_FILE, dir.display()))
+            .and_then(|path| Key::from_hex(&fs::read_to_string(path)?))
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, plaintext)
+        .expect("Encrypting into memory should not fail");
+
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, sealed: &[u8]) -> eyre::Result<Vec<u8>> {
+    let body = sealed
+        .strip_prefix(MAGIC)
+        .filter(|x| x.len() >= NONCE_LENGTH)
+        .ok_or_else(|| eyre!("Not a vault file"))?;
+    let (nonce, ciphertext) = body.split_at(NONCE_LENGTH);
+
+    key.cipher()
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| eyre!("Could not decrypt, the key is wrong or the file was modified"))
+}
+
+/// Where the encrypted copy of `path` is kept, e.g. `input.txt.vault` for `input.txt`.
+pub fn vault_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+    name.push(".vault");
+
+    path.with_file_name(name)
+}
+
+fn read_optional(path: &Path) -> eyre::Result<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).wrap_err_with(|| format!("Could not read {}", path.display())),
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum State {
+    Missing,
+    PlaintextOnly,
+    VaultOnly,
+    InSync,
+    /// The plaintext has changed since it was last encrypted, or the vault since it was decrypted.
+    Stale,
+}
+
+pub fn state(key: &Key, path: &Path) -> eyre::Result<State> {
+    let plaintext = read_optional(path)?;
+    let sealed = read_optional(&vault_path(path))?;
+
+    Ok(match (plaintext, sealed) {
+        (None, None) => State::Missing,
+        (Some(_), None) => State::PlaintextOnly,
+        (None, Some(_)) => State::VaultOnly,
+        (Some(plaintext), Some(sealed)) => match decrypt(key, &sealed)? == plaintext {
+            true => State::InSync,
+            false => State::Stale,
+        },
+    })
+}
+
+/// Encrypts `path` into its vault file unless the vault already holds the same contents, so
+/// unchanged inputs don't get new ciphertext on every run.
+pub fn seal(key: &Key, path: &Path) -> eyre::Result<bool> {
+    match state(key, path)? {
+        State::PlaintextOnly | State::Stale => {
+            replace(&vault_path(path), &encrypt(key, &fs::read(path)?))?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Decrypts the vault file for `path`. Plaintext that differs from the vault is only replaced
+/// when `force` is set.
+pub fn open(key: &Key, path: &Path, force: bool) -> eyre::Result<bool> {
+    match state(key, path)? {
+        State::VaultOnly => {}
+        State::Stale if force => {}
+        State::Stale => {
+            return Err(eyre!(
+                "{} differs from its vault file, encrypt it or pass --force to overwrite it",
+                path.display()
+            ))
+        }
+        _ => return Ok(false),
+    }
+
+    replace(path, &decrypt(key, &fs::read(vault_path(path))?)?)?;
+    Ok(true)
+}
+
+/// Writes `bytes` to a temporary file beside `path` and renames it over `path`, so anything
+/// that has the old file mapped keeps seeing it whole.
+pub fn replace(path: &Path, bytes: &[u8]) -> eyre::Result<()> {
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(format!(".{}.tmp", std::process::id()));
+    let temporary = path.with_file_name(name);
+
+    fs::write(&temporary, bytes).wrap_err_with(|| format!("Could not write {}", temporary.display()))?;
+    fs::rename(&temporary, path).wrap_err_with(|| format!("Could not replace {}", path.display()))
+}
+
+/// Reads `path`, falling back to decrypting its vault file in memory when the plaintext is
+/// absent. Returns `None` when neither exists.
+pub fn read(path: &Path) -> eyre::Result<Option<String>> {
+    let dir = path.parent().unwrap_or(Path::new("."));
+
+    let bytes = match read_optional(path)? {
+        Some(plaintext) => {
+            warn_if_stale(path, plaintext.len() as u64);
+            plaintext
+        }
+        None => match read_optional(&vault_path(path))? {
+            Some(sealed) => decrypt(&Key::find(dir)?, &sealed)?,
+            None => return Ok(None),
+        },
+    };
+
+    String::from_utf8(bytes)
+        .map(Some)
+        .map_err(|_| eyre!("{} is not valid UTF-8", path.display()))
+}
+
+/// Warns when the vault file can't hold plaintext of this length. Reading and decrypting both
+/// on every load would copy large inputs twice, so edits that keep the length are left to
+/// `aoc vault status`, which compares the contents.
+fn warn_if_stale(path: &Path, length: u64) {
+    if fs::metadata(vault_path(path)).is_ok_and(|x| x.len() != sealed_length(length)) {
+        eprintln!("warning: {} differs from its vault file", path.display());
+    }
+}
+
+/// How long the vault file for plaintext of this length is.
+fn sealed_length(length: u64) -> u64 {
+    (MAGIC.len() + NONCE_LENGTH + TAG_LENGTH) as u64 + length
+}
+
+/// Reads a day's `input.txt` from its crate directory, mapping a large plaintext into memory, or
+/// decrypting the vault file when there is no plaintext.
+pub fn read_input(day_dir: impl AsRef<Path>) -> eyre::Result<Input> {
+    let path = day_dir.as_ref().join("input.txt");
+    if let Ok(metadata) = fs::metadata(&path) {
+        warn_if_stale(&path, metadata.len());
+        return Input::load(&path);
+    }
+
+    read(&path)?
+        .map(Input::Owned)
+        .ok_or_else(|| eyre!("No input at {} or {}", path.display(), vault_path(&path).display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("vault-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn should_round_trip_through_encryption() {
+        // Given
+        let key = Key::generate();
+        let plaintext = b"3   4\n4   3\n";
+
+        // When
+        let sealed = encrypt(&key, plaintext);
+
+        // Then
+        assert_ne!(plaintext.as_slice(), &sealed[MAGIC.len() + NONCE_LENGTH..]);
+        assert_eq!(plaintext.to_vec(), decrypt(&key, &sealed).unwrap());
+    }
+
+    #[test]
+    fn should_reject_wrong_key_and_tampering() {
+        // Given
+        let key = Key::generate();
+        let mut sealed = encrypt(&key, b"secret");
+
+        // When
+        let wrong_key = decrypt(&Key::generate(), &sealed);
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        let tampered = decrypt(&key, &sealed);
+
+        // Then
+        assert!(wrong_key.is_err());
+        assert!(tampered.is_err());
+    }
+
+    #[test]
+    fn should_round_trip_key_as_hex() {
+        // Given
+        let key = Key::generate();
+
+        // When
+        let parsed = Key::from_hex(&key.to_hex()).unwrap();
+
+        // Then
+        assert_eq!(key.0, parsed.0);
+        assert!(Key::from_hex("abc").is_err());
+    }
+
+    #[test]
+    fn should_detect_stale_plaintext_and_reseal() {
+        // Given
+        let dir = temp_dir("stale");
+        let key = Key::generate();
+        let input = dir.join("input.txt");
+        fs::write(&input, "1 2").unwrap();
+
+        // When
+        let first_state = state(&key, &input).unwrap();
+        let sealed = seal(&key, &input).unwrap();
+        let resealed = seal(&key, &input).unwrap();
+        fs::write(&input, "1 3").unwrap();
+        let edited_state = state(&key, &input).unwrap();
+
+        // Then
+        assert_eq!(State::PlaintextOnly, first_state);
+        assert!(sealed);
+        assert!(!resealed);
+        assert_eq!(State::Stale, edited_state);
+        assert!(open(&key, &input, false).is_err());
+        assert_eq!(sealed_length(3), fs::metadata(vault_path(&input)).unwrap().len());
+        assert_eq!(2, fs::read_dir(&dir).unwrap().count());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn should_read_input_from_vault_when_plaintext_is_absent() {
+        // Given
+        let dir = temp_dir("read");
+        let day_dir = dir.join("day_01");
+        fs::create_dir_all(&day_dir).unwrap();
+        let key = Key::generate();
+        fs::write(dir.join(KEY_FILE), key.to_hex()).unwrap();
+        fs::write(vault_path(&day_dir.join("input.txt")), encrypt(&key, b"3 4\n")).unwrap();
+
+        // When
+        let sealed = read_input(&day_dir).unwrap();
+        fs::write(day_dir.join("input.txt"), "3 4\n1 2\n").unwrap();
+        let mapped = read_input(&day_dir).unwrap();
+
+        // Then
+        assert_eq!("3 4\n", &*sealed);
+        assert!(matches!(mapped, Input::Owned(_)));
+        assert_eq!("3 4\n1 2\n", &*mapped);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}